@@ -0,0 +1,103 @@
+use std::cmp::Reverse;
+
+use crate::{bignum::BigUint, ext_num_traits, FixedDecimal};
+
+#[expect(private_bounds)]
+impl<T: ext_num_traits::Widen, const E: u8> FixedDecimal<T, E> {
+    /// Distributes `self` proportionally to `ratios` at the scale `E`, the
+    /// parts always summing exactly to `self`.
+    ///
+    /// Every part is first rounded toward zero, then the units left over go
+    /// one each to the parts with the largest discarded remainders, the
+    /// earliest part winning ties (largest remainder method). Negative
+    /// amounts are allocated as their magnitude and negated back.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ratios` is empty or sums to zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI64;
+    /// # use std::str::FromStr;
+    /// #
+    /// let fee = FixedDecimalI64::<2>::from_str("10.00").unwrap();
+    /// let parts = fee.allocate(&[1u8, 1, 1]);
+    /// assert_eq!(parts.iter().map(ToString::to_string).collect::<Vec<_>>(), ["3.34", "3.33", "3.33"]);
+    ///
+    /// let parts = fee.allocate(&[70u8, 20, 10]);
+    /// assert_eq!(parts.iter().map(ToString::to_string).collect::<Vec<_>>(), ["7.00", "2.00", "1.00"]);
+    /// ```
+    #[must_use]
+    pub fn allocate<R: Copy + Into<u128>>(&self, ratios: &[R]) -> Vec<Self> {
+        let ratios: Vec<u128> = ratios.iter().map(|&ratio| ratio.into()).collect();
+        let total = ratios.iter().fold(BigUint::zero(), |total, &ratio| {
+            total.add(&BigUint::from_u128(ratio))
+        });
+        assert!(
+            !total.is_zero(),
+            "attempt to allocate with a zero total ratio"
+        );
+
+        let negative = !self.0.is_positive();
+        let magnitude: u128 = self.0.widen().try_into().ok().expect("magnitudes fit u128");
+        let amount = BigUint::from_u128(magnitude);
+        let (mut parts, remainders): (Vec<u128>, Vec<BigUint>) = ratios
+            .iter()
+            .map(|&ratio| {
+                let (part, remainder) = amount.mul(&BigUint::from_u128(ratio)).divrem(&total);
+                (
+                    part.to_u128().expect("part is at most the amount"),
+                    remainder,
+                )
+            })
+            .unzip();
+
+        // The truncated parts lose less than an unit each, so less units
+        // than there are parts are left over.
+        let leftover = magnitude - parts.iter().sum::<u128>();
+        let mut order: Vec<usize> = (0..parts.len()).collect();
+        order.sort_by_key(|&i| Reverse(&remainders[i]));
+        for &i in order.iter().take(leftover as usize) {
+            parts[i] += 1;
+        }
+
+        parts
+            .into_iter()
+            .map(|part| {
+                let part = T::Wide::try_from(part).ok();
+                Self(
+                    part.and_then(|part| T::narrow(negative, part))
+                        .expect("part is at most the amount"),
+                )
+            })
+            .collect()
+    }
+
+    /// Splits `self` into `n` parts at the scale `E` that sum exactly to
+    /// `self`, the first parts getting the units left over.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI64;
+    /// # use std::str::FromStr;
+    /// #
+    /// let amount = FixedDecimalI64::<2>::from_str("-100.00").unwrap();
+    /// let installments = amount.split_evenly(3);
+    /// assert_eq!(
+    ///     installments.iter().map(ToString::to_string).collect::<Vec<_>>(),
+    ///     ["-33.34", "-33.33", "-33.33"]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn split_evenly(&self, n: usize) -> Vec<Self> {
+        assert!(n != 0, "attempt to split into zero parts");
+        self.allocate(&vec![1u8; n])
+    }
+}
@@ -0,0 +1,312 @@
+use std::cmp::Ordering;
+
+/// Arbitrary precision unsigned integer, little endian `u64` limbs without
+/// trailing zero limbs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct BigUint(Vec<u64>);
+
+const TEN_POW_19: u64 = 10_000_000_000_000_000_000;
+
+impl BigUint {
+    pub(crate) fn zero() -> Self {
+        Self(Vec::new())
+    }
+
+    pub(crate) fn from_u128(n: u128) -> Self {
+        Self(vec![n as u64, (n >> 64) as u64]).normalized()
+    }
+
+    /// `10^exp`.
+    pub(crate) fn pow10(exp: u32) -> Self {
+        let mut result = Self::from_u128(1);
+        for _ in 0..exp / 19 {
+            result = result.mul_small(TEN_POW_19);
+        }
+        result.mul_small(10u64.pow(exp % 19))
+    }
+
+    /// `self^exp`.
+    pub(crate) fn pow(&self, mut exp: u64) -> Self {
+        let mut base = self.clone();
+        let mut result = Self::from_u128(1);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.mul(&base);
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base.mul(&base);
+            }
+        }
+        result
+    }
+
+    /// Integer `n`th root rounded down and whether it is exact.
+    pub(crate) fn nth_root(&self, n: u32) -> (Self, bool) {
+        if self.is_zero() || n == 1 {
+            return (self.clone(), true);
+        }
+        // `self < 2^n`, the root is one.
+        if u64::from(n) >= self.bits() {
+            return (Self::from_u128(1), *self == Self::from_u128(1));
+        }
+        // Newton iteration `x = ((n - 1) * x + self / x^(n - 1)) / n`, any
+        // positive `x` gives a step that is not below the root, from which
+        // it decreases toward it.
+        let n_big = Self::from_u128(n.into());
+        let step = |x: &Self| {
+            let (quotient, _) = self.divrem(&x.pow((n - 1).into()));
+            x.mul_small((n - 1).into()).add(&quotient).divrem(&n_big).0
+        };
+        let mut x = step(&self.root_estimate(n));
+        loop {
+            let next = step(&x);
+            if next >= x {
+                break;
+            }
+            x = next;
+        }
+        let exact = x.pow(n.into()) == *self;
+        (x, exact)
+    }
+
+    /// Positive estimate of the `n`th root from the leading bits, so Newton
+    /// iteration only has a few steps left.
+    fn root_estimate(&self, n: u32) -> Self {
+        let shift = self.bits().saturating_sub(64);
+        let leading = self.shr(shift).to_u128().expect("at most 64 bits") as f64;
+        let log2 = (shift as f64 + leading.log2()) / f64::from(n);
+        // `2^log2 = 2^(fraction + 64) * 2^(exponent - 64)`.
+        let exponent = log2.floor();
+        let mantissa = Self::from_u128((log2 - exponent + 64.0).exp2() as u128);
+        let estimate = if exponent >= 64.0 {
+            mantissa.shl(exponent as u64 - 64)
+        } else {
+            mantissa.shr(64 - exponent as u64)
+        };
+        if estimate.is_zero() {
+            Self::from_u128(1)
+        } else {
+            estimate
+        }
+    }
+
+    fn normalized(mut self) -> Self {
+        while self.0.last() == Some(&0) {
+            self.0.pop();
+        }
+        self
+    }
+
+    pub(crate) fn is_zero(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub(crate) fn to_u128(&self) -> Option<u128> {
+        match self.0[..] {
+            [] => Some(0),
+            [low] => Some(low.into()),
+            [low, high] => Some(u128::from(low) | u128::from(high) << 64),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn bits(&self) -> u64 {
+        self.0.last().map_or(0, |last| {
+            self.0.len() as u64 * 64 - u64::from(last.leading_zeros())
+        })
+    }
+
+    /// Number of decimal digits, `0` for zero.
+    pub(crate) fn digits(&self) -> u32 {
+        let bits = self.bits();
+        if bits == 0 {
+            return 0;
+        }
+        // Digits of `2^(bits - 1)`, `self` has that many or one more.
+        let estimate = (u128::from(bits - 1) * 30_102_999_566 / 100_000_000_000) as u32 + 1;
+        if *self >= Self::pow10(estimate) {
+            estimate + 1
+        } else {
+            estimate
+        }
+    }
+
+    pub(crate) fn add(&self, rhs: &Self) -> Self {
+        let (long, short) = if self.0.len() >= rhs.0.len() {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+        let mut limbs = Vec::with_capacity(long.0.len() + 1);
+        let mut carry = false;
+        for (i, &a) in long.0.iter().enumerate() {
+            let (sum, c1) = a.overflowing_add(short.0.get(i).copied().unwrap_or(0));
+            let (sum, c2) = sum.overflowing_add(carry.into());
+            limbs.push(sum);
+            carry = c1 || c2;
+        }
+        limbs.push(carry.into());
+        Self(limbs).normalized()
+    }
+
+    /// `self - rhs`, `rhs` must not be bigger than `self`.
+    pub(crate) fn sub(&self, rhs: &Self) -> Self {
+        debug_assert!(*self >= *rhs);
+        let mut limbs = Vec::with_capacity(self.0.len());
+        let mut borrow = false;
+        for (i, &a) in self.0.iter().enumerate() {
+            let (diff, b1) = a.overflowing_sub(rhs.0.get(i).copied().unwrap_or(0));
+            let (diff, b2) = diff.overflowing_sub(borrow.into());
+            limbs.push(diff);
+            borrow = b1 || b2;
+        }
+        Self(limbs).normalized()
+    }
+
+    pub(crate) fn mul(&self, rhs: &Self) -> Self {
+        if self.is_zero() || rhs.is_zero() {
+            return Self::zero();
+        }
+        let mut limbs = vec![0u64; self.0.len() + rhs.0.len()];
+        for (i, &a) in self.0.iter().enumerate() {
+            let mut carry = 0u128;
+            for (j, &b) in rhs.0.iter().enumerate() {
+                let t = u128::from(a) * u128::from(b) + u128::from(limbs[i + j]) + carry;
+                limbs[i + j] = t as u64;
+                carry = t >> 64;
+            }
+            limbs[i + rhs.0.len()] = carry as u64;
+        }
+        Self(limbs).normalized()
+    }
+
+    pub(crate) fn mul_small(&self, rhs: u64) -> Self {
+        let mut limbs = Vec::with_capacity(self.0.len() + 1);
+        let mut carry = 0u128;
+        for &a in &self.0 {
+            let t = u128::from(a) * u128::from(rhs) + carry;
+            limbs.push(t as u64);
+            carry = t >> 64;
+        }
+        limbs.push(carry as u64);
+        Self(limbs).normalized()
+    }
+
+    pub(crate) fn shl(&self, bits: u64) -> Self {
+        let (limbs, bits) = ((bits / 64) as usize, (bits % 64) as u32);
+        let mut result = vec![0u64; limbs];
+        let mut carry = 0u64;
+        for &a in &self.0 {
+            result.push(a << bits | carry);
+            carry = if bits == 0 { 0 } else { a >> (64 - bits) };
+        }
+        result.push(carry);
+        Self(result).normalized()
+    }
+
+    fn shr(&self, bits: u64) -> Self {
+        let (limbs, bits) = ((bits / 64) as usize, (bits % 64) as u32);
+        let high = self.0.get(limbs..).unwrap_or_default();
+        let result = high
+            .iter()
+            .enumerate()
+            .map(|(i, &a)| {
+                let carry = match high.get(i + 1) {
+                    Some(&next) if bits != 0 => next << (64 - bits),
+                    _ => 0,
+                };
+                a >> bits | carry
+            })
+            .collect();
+        Self(result).normalized()
+    }
+
+    fn divrem_small(&self, rhs: u64) -> (Self, Self) {
+        let mut limbs = vec![0u64; self.0.len()];
+        let mut remainder = 0u128;
+        for (i, &a) in self.0.iter().enumerate().rev() {
+            let t = remainder << 64 | u128::from(a);
+            limbs[i] = (t / u128::from(rhs)) as u64;
+            remainder = t % u128::from(rhs);
+        }
+        (Self(limbs).normalized(), Self::from_u128(remainder))
+    }
+
+    /// Quotient and remainder of `self / rhs`, `rhs` must not be zero.
+    pub(crate) fn divrem(&self, rhs: &Self) -> (Self, Self) {
+        assert!(!rhs.is_zero(), "attempt to divide by zero");
+        if let [rhs] = rhs.0[..] {
+            return self.divrem_small(rhs);
+        }
+        if *self < *rhs {
+            return (Self::zero(), self.clone());
+        }
+        // Knuth's algorithm D: schoolbook division estimating every quotient
+        // limb from the top limbs, normalized so the estimate is off by at
+        // most two.
+        let shift = u64::from(rhs.0.last().expect("non zero").leading_zeros());
+        let divisor = rhs.shl(shift).0;
+        let mut dividend = self.shl(shift).0;
+        dividend.resize(self.0.len() + 1, 0);
+        let n = divisor.len();
+        let (top, second) = (u128::from(divisor[n - 1]), u128::from(divisor[n - 2]));
+        let mut quotient = vec![0u64; dividend.len() - n];
+        for j in (0..quotient.len()).rev() {
+            let head = u128::from(dividend[j + n]) << 64 | u128::from(dividend[j + n - 1]);
+            let (mut estimate, mut rest) = (head / top, head % top);
+            while estimate > u128::from(u64::MAX)
+                || estimate * second > (rest << 64 | u128::from(dividend[j + n - 2]))
+            {
+                estimate -= 1;
+                rest += top;
+                if rest > u128::from(u64::MAX) {
+                    break;
+                }
+            }
+            // Subtract `estimate * divisor` from the current window.
+            let (mut borrow, mut carry) = (0i128, 0u128);
+            for (i, &d) in divisor.iter().enumerate() {
+                let product = estimate * u128::from(d) + carry;
+                carry = product >> 64;
+                let t = i128::from(dividend[i + j]) - borrow - i128::from(product as u64);
+                dividend[i + j] = t as u64;
+                borrow = i128::from(t < 0);
+            }
+            let t = i128::from(dividend[j + n]) - borrow - carry as i128;
+            dividend[j + n] = t as u64;
+            if t < 0 {
+                // The estimate was one too big, add the divisor back.
+                estimate -= 1;
+                let mut carry = 0u128;
+                for (i, &d) in divisor.iter().enumerate() {
+                    let sum = u128::from(dividend[i + j]) + u128::from(d) + carry;
+                    dividend[i + j] = sum as u64;
+                    carry = sum >> 64;
+                }
+                dividend[j + n] = dividend[j + n].wrapping_add(carry as u64);
+            }
+            quotient[j] = estimate as u64;
+        }
+        dividend.truncate(n);
+        (
+            Self(quotient).normalized(),
+            Self(dividend).normalized().shr(shift),
+        )
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0
+            .len()
+            .cmp(&other.0.len())
+            .then_with(|| self.0.iter().rev().cmp(other.0.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
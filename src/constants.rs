@@ -1,2 +1,5 @@
 // The maximum string buffer size used for serialization purposes.
 pub const MAX_STR_BUFFER_SIZE: usize = u8::MAX as usize + 2 /*0.*/ + 1/*-*/ + 1/*align*/;
+
+// The longest exact decimal expansion of a `f64`, reached by subnormals.
+pub(crate) const F64_STR_BUFFER_SIZE: usize = 1074 /*2^-1074*/ + 2 /*0.*/;
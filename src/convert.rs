@@ -0,0 +1,516 @@
+use std::{cmp::Ordering, fmt::Write, num::TryFromIntError, ops, str::FromStr};
+
+use arrayvec::ArrayString;
+use num_traits::{CheckedAdd, CheckedMul, ConstZero};
+
+use crate::{
+    constants::F64_STR_BUFFER_SIZE,
+    ext_num_traits::{self, ConstTen},
+    rounding, ArithmeticError, FixedDecimal, RoundingStrategy,
+};
+
+macro_rules! from_impl {
+    ($from:ty => $($to:ty),+) => {$(
+        impl<const E: u8> From<FixedDecimal<$from, E>> for FixedDecimal<$to, E> {
+            fn from(value: FixedDecimal<$from, E>) -> Self {
+                Self(value.0.into())
+            }
+        }
+    )+};
+}
+
+macro_rules! try_from_impl {
+    ($from:ty => $($to:ty),+) => {$(
+        impl<const E: u8> TryFrom<FixedDecimal<$from, E>> for FixedDecimal<$to, E> {
+            type Error = TryFromIntError;
+
+            fn try_from(value: FixedDecimal<$from, E>) -> Result<Self, Self::Error> {
+                value.0.try_into().map(Self)
+            }
+        }
+    )+};
+}
+
+from_impl!(i8 => i16, i32, i64, i128);
+from_impl!(i16 => i32, i64, i128);
+from_impl!(i32 => i64, i128);
+from_impl!(i64 => i128);
+from_impl!(u8 => u16, u32, u64, u128, i16, i32, i64, i128);
+from_impl!(u16 => u32, u64, u128, i32, i64, i128);
+from_impl!(u32 => u64, u128, i64, i128);
+from_impl!(u64 => u128, i128);
+
+try_from_impl!(i8 => u8, u16, u32, u64, u128);
+try_from_impl!(i16 => i8, u8, u16, u32, u64, u128);
+try_from_impl!(i32 => i8, i16, u8, u16, u32, u64, u128);
+try_from_impl!(i64 => i8, i16, i32, u8, u16, u32, u64, u128);
+try_from_impl!(i128 => i8, i16, i32, i64, u8, u16, u32, u64, u128);
+try_from_impl!(u8 => i8);
+try_from_impl!(u16 => i8, i16, u8);
+try_from_impl!(u32 => i8, i16, i32, u8, u16);
+try_from_impl!(u64 => i8, i16, i32, i64, u8, u16, u32);
+try_from_impl!(u128 => i8, i16, i32, i64, i128, u8, u16, u32, u64);
+
+macro_rules! unsigned_abs_impl {
+    ($($tty:ty => $utty:ty),+) => {$(
+        impl<const E: u8> FixedDecimal<$tty, E> {
+            /// Absolute value as a decimal of the same scale backed by the
+            /// unsigned counterpart of the backing type, which never
+            /// overflows.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # use rust_fixed_decimal::{FixedDecimalI8, FixedDecimalU8};
+            /// #
+            /// let a = FixedDecimalI8::<1>::MIN;
+            /// assert_eq!(a.unsigned_abs(), FixedDecimalU8::<1>::new(128));
+            /// ```
+            #[must_use]
+            pub fn unsigned_abs(self) -> FixedDecimal<$utty, E> {
+                FixedDecimal(self.0.unsigned_abs())
+            }
+        }
+    )+};
+}
+
+unsigned_abs_impl!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128);
+
+/// Exact decimal expansion of `|value|`.
+fn f64_to_exact_str(value: f64) -> ArrayString<F64_STR_BUFFER_SIZE> {
+    const MANTISSA_BITS: u32 = 52;
+    let bits = value.to_bits();
+    let biased = ((bits >> MANTISSA_BITS) & 0x7ff) as i32;
+    let fraction = bits & ((1 << MANTISSA_BITS) - 1);
+    let (mantissa, exp) = if biased == 0 {
+        (fraction, -1074)
+    } else {
+        (fraction | (1 << MANTISSA_BITS), biased - 1075)
+    };
+    // `2^-k` needs exactly `k` fractional digits.
+    let precision = if mantissa == 0 {
+        0
+    } else {
+        (-(exp + mantissa.trailing_zeros() as i32)).max(0) as usize
+    };
+
+    let mut rep = ArrayString::new();
+    write!(rep, "{:.*}", precision, value.abs()).expect("buffer fits any f64");
+    rep
+}
+
+/// Shortest decimal expansion of `|value|` that parses back to it.
+fn f64_to_shortest_str(value: f64) -> ArrayString<F64_STR_BUFFER_SIZE> {
+    let mut rep = ArrayString::new();
+    write!(rep, "{}", value.abs()).expect("buffer fits any f64");
+    rep
+}
+
+/// Rounds the exact decimal expansion of a float to the scale `E`.
+fn from_f64_rounded<T: ext_num_traits::Widen, const E: u8>(
+    value: f64,
+    strategy: Option<RoundingStrategy>,
+) -> Result<FixedDecimal<T, E>, ArithmeticError> {
+    from_f64_rep(value, f64_to_exact_str, strategy)
+}
+
+/// Rounds the decimal expansion of a float given by `to_str` to the scale `E`.
+fn from_f64_rep<T: ext_num_traits::Widen, const E: u8>(
+    value: f64,
+    to_str: fn(f64) -> ArrayString<F64_STR_BUFFER_SIZE>,
+    strategy: Option<RoundingStrategy>,
+) -> Result<FixedDecimal<T, E>, ArithmeticError> {
+    if value.is_nan() {
+        return Err(ArithmeticError::NaN);
+    }
+    let negative = value.is_sign_negative();
+    if value.is_infinite() {
+        return Err(ArithmeticError::overflow(negative));
+    }
+
+    let rep = to_str(value);
+    let (integer, fraction) = rep.split_once('.').unwrap_or((rep.as_str(), ""));
+    let scale: usize = E.into();
+    let (kept, dropped) = fraction.split_at(fraction.len().min(scale));
+
+    let overflow = ArithmeticError::overflow(negative);
+    let mut acc = T::Wide::ZERO;
+    for digit in integer.bytes().chain(kept.bytes()) {
+        acc = acc
+            .checked_mul(&T::Wide::TEN)
+            .and_then(|acc| acc.checked_add(&T::Wide::from(digit - b'0')))
+            .ok_or(overflow.clone())?;
+    }
+    let padding = (scale - kept.len()).try_into().expect("scale fits u32");
+    acc = rounding::mul_pow10(acc, padding).ok_or(overflow.clone())?;
+
+    let mut dropped = dropped.bytes();
+    let remainder = dropped.next().and_then(|first| {
+        let sticky = dropped.any(|d| d != b'0');
+        match (first.cmp(&b'5'), sticky) {
+            (Ordering::Less, false) if first == b'0' => None,
+            (Ordering::Equal, true) => Some(Ordering::Greater),
+            (half, _) => Some(half),
+        }
+    });
+    let strategy = match (strategy, remainder) {
+        (None, Some(_)) => return Err(ArithmeticError::Underflow),
+        (strategy, _) => strategy.unwrap_or_default(),
+    };
+    rounding::round_quotient(acc, remainder, negative, strategy)
+        .and_then(|rounded| T::narrow(negative, rounded))
+        .map(FixedDecimal)
+        .ok_or(overflow)
+}
+
+#[expect(private_bounds)]
+impl<T: ext_num_traits::Widen, const E: u8> FixedDecimal<T, E> {
+    /// Nearest value of scale `E` to the float, rounding with the given
+    /// strategy.
+    ///
+    /// The rounding is applied to the exact binary value of the float, so
+    /// `0.1f64` which is slightly above `0.1` rounds up at scale 20.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::{ArithmeticError, FixedDecimalI64, RoundingStrategy};
+    /// #
+    /// let a = FixedDecimalI64::<2>::from_f64_with_rounding(2.675, RoundingStrategy::MidpointAwayFromZero);
+    /// assert_eq!(a.unwrap().to_string(), "2.67");
+    /// let a = FixedDecimalI64::<2>::from_f64_with_rounding(-1.001, RoundingStrategy::ToNegativeInfinity);
+    /// assert_eq!(a.unwrap().to_string(), "-1.01");
+    /// let a = FixedDecimalI64::<2>::from_f64_with_rounding(f64::NAN, RoundingStrategy::default());
+    /// assert_eq!(a, Err(ArithmeticError::NaN));
+    /// ```
+    pub fn from_f64_with_rounding(
+        value: f64,
+        strategy: RoundingStrategy,
+    ) -> Result<Self, ArithmeticError> {
+        from_f64_rounded(value, Some(strategy))
+    }
+
+    /// Converts a float only if it is exactly representable at scale `E`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::{ArithmeticError, FixedDecimalI64};
+    /// #
+    /// let a = FixedDecimalI64::<3>::from_f64_exact(-2.125);
+    /// assert_eq!(a.unwrap().to_string(), "-2.125");
+    /// let a = FixedDecimalI64::<3>::from_f64_exact(0.1);
+    /// assert_eq!(a, Err(ArithmeticError::Underflow));
+    /// ```
+    pub fn from_f64_exact(value: f64) -> Result<Self, ArithmeticError> {
+        from_f64_rounded(value, None)
+    }
+}
+
+/// Nearest value of scale `E` to the float, rounding with
+/// [`RoundingStrategy::MidpointNearestEven`].
+/// See [`FixedDecimal::from_f64_with_rounding`].
+impl<T: ext_num_traits::Widen, const E: u8> TryFrom<f64> for FixedDecimal<T, E> {
+    type Error = ArithmeticError;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        from_f64_rounded(value, Some(RoundingStrategy::default()))
+    }
+}
+
+/// Nearest value of scale `E` to the float, rounding with
+/// [`RoundingStrategy::MidpointNearestEven`].
+/// See [`FixedDecimal::from_f64_with_rounding`].
+impl<T: ext_num_traits::Widen, const E: u8> TryFrom<f32> for FixedDecimal<T, E> {
+    type Error = ArithmeticError;
+
+    fn try_from(value: f32) -> Result<Self, Self::Error> {
+        from_f64_rounded(value.into(), Some(RoundingStrategy::default()))
+    }
+}
+
+#[expect(private_bounds)]
+impl<T, const E: u8> FixedDecimal<T, E>
+where
+    T: ext_num_traits::UAbs + num_traits::Zero + ext_num_traits::Sign + Copy,
+    <T as ext_num_traits::ExtSigned>::Unsigned: ext_num_traits::ILog10
+        + ext_num_traits::Ten
+        + TryInto<u8>
+        + std::fmt::Debug
+        + Copy
+        + num_traits::Pow<u8, Output = <T as ext_num_traits::ExtSigned>::Unsigned>,
+    <<T as ext_num_traits::ExtSigned>::Unsigned as TryInto<u8>>::Error: std::fmt::Debug,
+{
+    fn to_float<F: FromStr + ops::Neg<Output = F>>(self) -> F
+    where
+        <F as FromStr>::Err: std::fmt::Debug,
+    {
+        let (rep, _) = crate::str::to_str_internal(&self, None);
+        let value: F = rep
+            .parse()
+            .expect("decimal representation is a valid float");
+        if self.0.is_positive() {
+            value
+        } else {
+            -value
+        }
+    }
+
+    /// Nearest `f64` to the decimal, ties rounded to even.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI64;
+    /// # use std::str::FromStr;
+    /// #
+    /// let a = FixedDecimalI64::<2>::from_str("-0.30").unwrap();
+    /// assert_eq!(a.to_f64(), -0.3);
+    /// ```
+    #[must_use]
+    pub fn to_f64(self) -> f64 {
+        self.to_float()
+    }
+
+    /// Nearest `f32` to the decimal, ties rounded to even.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI64;
+    /// # use std::str::FromStr;
+    /// #
+    /// let a = FixedDecimalI64::<2>::from_str("0.30").unwrap();
+    /// assert_eq!(a.to_f32(), 0.3);
+    /// ```
+    #[must_use]
+    pub fn to_f32(self) -> f32 {
+        self.to_float()
+    }
+}
+
+#[expect(private_bounds)]
+impl<T: ext_num_traits::Widen, const E: u8> FixedDecimal<T, E> {
+    /// Places an integer at scale `E`, i.e. `from_int(5)` is `5.00` at scale 2.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n * 10^E` overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI64;
+    /// #
+    /// let five = FixedDecimalI64::<2>::from_int(5);
+    /// assert_eq!(five.to_string(), "5.00");
+    /// assert_eq!(five.mantissa(), 500);
+    /// ```
+    #[must_use]
+    pub fn from_int(n: T) -> Self {
+        Self::checked_from_int(n).expect("attempt to scale integer with overflow")
+    }
+
+    /// Places an integer at scale `E`.
+    /// Returns `None` if `n * 10^E` overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI8;
+    /// #
+    /// assert_eq!(FixedDecimalI8::<1>::checked_from_int(-12), Some(FixedDecimalI8::new(-120)));
+    /// assert_eq!(FixedDecimalI8::<1>::checked_from_int(13), None);
+    /// ```
+    #[must_use]
+    pub fn checked_from_int(n: T) -> Option<Self> {
+        let negative = !n.is_positive();
+        T::narrow(negative, rounding::mul_pow10(n.widen(), E.into())?).map(Self)
+    }
+
+    /// Builds a decimal from its integer part and the mantissa of its
+    /// fractional part, the inverse of [`FixedDecimal::into_parts`].
+    ///
+    /// Both parts must have the same sign, e.g. `-5.25` is `(-5, -25)` and
+    /// `-0.25` is `(0, -25)` at scale 2.
+    /// Returns `None` if the signs differ, the fraction has more than `E`
+    /// digits or the result overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI64;
+    /// #
+    /// let a = FixedDecimalI64::<2>::from_parts(-5, -25).unwrap();
+    /// assert_eq!(a.to_string(), "-5.25");
+    /// assert_eq!(FixedDecimalI64::<2>::from_parts(5, 7).unwrap().to_string(), "5.07");
+    /// assert_eq!(FixedDecimalI64::<2>::from_parts(5, -25), None);
+    /// assert_eq!(FixedDecimalI64::<2>::from_parts(5, 100), None);
+    /// ```
+    #[must_use]
+    pub fn from_parts(integer: T, fraction: T) -> Option<Self> {
+        let (integer_negative, fraction_negative) =
+            (!integer.is_positive(), !fraction.is_positive());
+        let (integer, fraction) = (integer.widen(), fraction.widen());
+        let zero = <T::Wide as ConstZero>::ZERO;
+        if integer_negative != fraction_negative && integer != zero && fraction != zero {
+            return None;
+        }
+        if rounding::pow10::<T::Wide>(E.into()).is_some_and(|one| fraction >= one) {
+            return None;
+        }
+        let magnitude = rounding::mul_pow10(integer, E.into())?.checked_add(&fraction)?;
+        T::narrow(integer_negative || fraction_negative, magnitude).map(Self)
+    }
+
+    /// Splits into the integer part and the mantissa of the fractional
+    /// part, both with the sign of `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI64;
+    /// # use std::str::FromStr;
+    /// #
+    /// let a = FixedDecimalI64::<2>::from_str("-5.25").unwrap();
+    /// assert_eq!(a.into_parts(), (-5, -25));
+    /// ```
+    #[must_use]
+    pub fn into_parts(self) -> (T, T) {
+        let negative = !self.0.is_positive();
+        let magnitude = self.0.widen();
+        let integer =
+            rounding::div_pow10_rounded(magnitude, E.into(), negative, RoundingStrategy::ToZero)
+                .and_then(|integer| T::narrow(negative, integer));
+        let fraction = T::narrow(negative, rounding::rem_pow10(magnitude, E.into()));
+        (
+            integer.expect("integer part is smaller than self"),
+            fraction.expect("fractional part is smaller than self"),
+        )
+    }
+
+    /// Rounds to an integer with the given strategy, returning it as the
+    /// backing type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::{FixedDecimalI64, RoundingStrategy};
+    /// # use std::str::FromStr;
+    /// #
+    /// let a = FixedDecimalI64::<2>::from_str("-5.50").unwrap();
+    /// assert_eq!(a.to_integer(RoundingStrategy::MidpointNearestEven), -6);
+    /// assert_eq!(a.to_integer(RoundingStrategy::ToZero), -5);
+    /// ```
+    #[must_use]
+    pub fn to_integer(self, strategy: RoundingStrategy) -> T {
+        let negative = !self.0.is_positive();
+        rounding::div_pow10_rounded(self.0.widen(), E.into(), negative, strategy)
+            .and_then(|integer| T::narrow(negative, integer))
+            .expect("rounded integer part is smaller than self")
+    }
+}
+
+macro_rules! int_try_from_impl {
+    ($($from:ty),+) => {$(
+        /// Places an integer at scale `E`, failing if `n * 10^E` overflows.
+        impl<T, const E: u8> TryFrom<$from> for FixedDecimal<T, E>
+        where
+            T: ext_num_traits::Widen + TryFrom<$from>,
+        {
+            type Error = ArithmeticError;
+
+            fn try_from(n: $from) -> Result<Self, Self::Error> {
+                let overflow = ArithmeticError::overflow(!ext_num_traits::Sign::is_positive(&n));
+                T::try_from(n)
+                    .ok()
+                    .and_then(Self::checked_from_int)
+                    .ok_or(overflow)
+            }
+        }
+    )+};
+}
+
+int_try_from_impl!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+
+macro_rules! primitive_impl {
+    ($($tty:ty),+) => {$(
+        /// Integer conversions truncate toward zero, i.e. `-1.9` is `-1`, and
+        /// return `None` if the integer part does not fit in the target.
+        /// Float conversions are correctly rounded.
+        impl<const E: u8> num_traits::ToPrimitive for FixedDecimal<$tty, E> {
+            fn to_i64(&self) -> Option<i64> {
+                self.into_parts().0.to_i64()
+            }
+
+            fn to_u64(&self) -> Option<u64> {
+                self.into_parts().0.to_u64()
+            }
+
+            fn to_i128(&self) -> Option<i128> {
+                self.into_parts().0.to_i128()
+            }
+
+            fn to_u128(&self) -> Option<u128> {
+                self.into_parts().0.to_u128()
+            }
+
+            fn to_f64(&self) -> Option<f64> {
+                Some(FixedDecimal::to_f64(*self))
+            }
+
+            fn to_f32(&self) -> Option<f32> {
+                Some(FixedDecimal::to_f32(*self))
+            }
+        }
+
+        /// Integers are placed at scale `E` and floats are truncated toward
+        /// zero to `E` digits, both return `None` on overflow.
+        ///
+        /// Floats are truncated from their shortest representation, the one
+        /// `Display` prints, so `0.29` is `0.29` at scale 2 even though the
+        /// closest `f64` is slightly smaller.
+        impl<const E: u8> num_traits::FromPrimitive for FixedDecimal<$tty, E> {
+            fn from_i64(n: i64) -> Option<Self> {
+                Self::try_from(n).ok()
+            }
+
+            fn from_u64(n: u64) -> Option<Self> {
+                Self::try_from(n).ok()
+            }
+
+            fn from_i128(n: i128) -> Option<Self> {
+                Self::try_from(n).ok()
+            }
+
+            fn from_u128(n: u128) -> Option<Self> {
+                Self::try_from(n).ok()
+            }
+
+            fn from_f64(n: f64) -> Option<Self> {
+                from_f64_rep(n, f64_to_shortest_str, Some(RoundingStrategy::ToZero)).ok()
+            }
+        }
+
+        /// Casts integer values exactly and anything else through
+        /// [`num_traits::ToPrimitive::to_f64`], with the [`num_traits::FromPrimitive`]
+        /// semantics.
+        impl<const E: u8> num_traits::NumCast for FixedDecimal<$tty, E> {
+            fn from<N: num_traits::ToPrimitive>(n: N) -> Option<Self> {
+                use num_traits::FromPrimitive;
+
+                let float = n.to_f64();
+                // Integer conversions truncate, only trust them if the float
+                // agrees that nothing was dropped.
+                let exact = |integer: f64| float.is_none() || float == Some(integer);
+                match (n.to_i128(), n.to_u128()) {
+                    (Some(integer), _) if exact(integer as f64) => Self::from_i128(integer),
+                    (_, Some(integer)) if exact(integer as f64) => Self::from_u128(integer),
+                    _ => Self::from_f64(float?),
+                }
+            }
+        }
+    )+};
+}
+
+primitive_impl!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
@@ -1 +1,23 @@
+/// Error returned by fallible arithmetic operations.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum ArithmeticError {
+    /// The result is bigger than [`FixedDecimal::MAX`](crate::FixedDecimal::MAX).
+    PosOverflow,
+    /// The result is smaller than [`FixedDecimal::MIN`](crate::FixedDecimal::MIN).
+    NegOverflow,
+    /// The result has more fractional digits than the scale can hold.
+    Underflow,
+    /// The input is not a number, e.g. [`f64::NAN`].
+    NaN,
+}
 
+impl ArithmeticError {
+    pub(crate) fn overflow(negative: bool) -> Self {
+        if negative {
+            Self::NegOverflow
+        } else {
+            Self::PosOverflow
+        }
+    }
+}
@@ -1,3 +1,7 @@
+use std::ops;
+
+use crate::wide::U256;
+
 pub(crate) trait ConstBound {
     const MAX: Self;
     const MIN: Self;
@@ -35,6 +39,81 @@ pub(crate) trait ConstTenPow<const E: u8> {
     const RESULT: Self;
 }
 
+/// Unsigned integer wide enough to hold the product of two magnitudes of the
+/// backing type it was widened from.
+pub(crate) trait WideUnsigned:
+    Copy
+    + Ord
+    + From<u8>
+    + num_traits::ConstZero
+    + num_traits::ConstOne
+    + ConstTen
+    + num_traits::CheckedAdd
+    + num_traits::CheckedSub
+    + num_traits::CheckedMul
+    + num_traits::WrappingMul
+    + ops::Div<Output = Self>
+    + ops::Rem<Output = Self>
+    + std::hash::Hash
+    + TryFrom<u128>
+    + TryInto<u128>
+{
+}
+
+impl<W> WideUnsigned for W where
+    W: Copy
+        + Ord
+        + From<u8>
+        + num_traits::ConstZero
+        + num_traits::ConstOne
+        + ConstTen
+        + num_traits::CheckedAdd
+        + num_traits::CheckedSub
+        + num_traits::CheckedMul
+        + num_traits::WrappingMul
+        + ops::Div<Output = Self>
+        + ops::Rem<Output = Self>
+        + std::hash::Hash
+        + TryFrom<u128>
+        + TryInto<u128>
+{
+}
+
+/// Conversion keeping only the low bits, as the `as` cast between primitive
+/// integers.
+pub(crate) trait Truncate<T> {
+    fn truncate(self) -> T;
+}
+
+macro_rules! truncate_impl {
+    ($($from:ty => $to:ty),*) => {
+        $(
+            impl Truncate<$to> for $from {
+                fn truncate(self) -> $to {
+                    self as $to
+                }
+            }
+        )*
+    };
+}
+
+truncate_impl!(u16 => u8, u32 => u16, u64 => u32, u128 => u64);
+
+pub(crate) trait Widen: Sign + Copy {
+    type Wide: WideUnsigned;
+
+    /// Absolute value of `self` as a wide unsigned integer.
+    fn widen(self) -> Self::Wide;
+
+    /// Builds back a value from its sign and magnitude, failing if it does
+    /// not fit.
+    fn narrow(negative: bool, wide: Self::Wide) -> Option<Self>;
+
+    /// Same as [`Widen::narrow`] but wrapping around at the boundary of the
+    /// type when it does not fit.
+    fn narrow_wrapping(negative: bool, wide: Self::Wide) -> Self;
+}
+
 macro_rules! num_impl {
     ($tty:ty) => {
         impl ConstBound for $tty {
@@ -59,7 +138,7 @@ macro_rules! num_impl {
 }
 
 macro_rules! sign_impl {
-    ($tty:ty, $utty:ty) => {
+    ($tty:ty, $utty:ty, $wtty:ty) => {
         impl ExtSigned for $tty {
             type Unsigned = $utty;
         }
@@ -70,10 +149,32 @@ macro_rules! sign_impl {
 
         impl UAbs for $tty {
             fn uabs(self) -> Self::Unsigned {
-                if self == Self::MIN {
-                    (Self::MAX as Self::Unsigned + 1)
+                self.unsigned_abs()
+            }
+        }
+
+        impl Widen for $tty {
+            type Wide = $wtty;
+
+            fn widen(self) -> Self::Wide {
+                self.unsigned_abs().into()
+            }
+
+            fn narrow(negative: bool, wide: Self::Wide) -> Option<Self> {
+                let magnitude = <$utty>::try_from(wide).ok()?;
+                if negative {
+                    (0 as $tty).checked_sub_unsigned(magnitude)
                 } else {
-                    self.abs() as Self::Unsigned
+                    (0 as $tty).checked_add_unsigned(magnitude)
+                }
+            }
+
+            fn narrow_wrapping(negative: bool, wide: Self::Wide) -> Self {
+                let value = Truncate::<$utty>::truncate(wide) as $tty;
+                if negative {
+                    value.wrapping_neg()
+                } else {
+                    value
                 }
             }
         }
@@ -89,7 +190,7 @@ macro_rules! sign_impl {
 }
 
 macro_rules! unsign_impl {
-    ($tty:ty) => {
+    ($tty:ty, $wtty:ty) => {
         impl ExtSigned for $tty {
             type Unsigned = Self;
         }
@@ -103,6 +204,31 @@ macro_rules! unsign_impl {
             }
         }
 
+        impl Widen for $tty {
+            type Wide = $wtty;
+
+            fn widen(self) -> Self::Wide {
+                self.into()
+            }
+
+            fn narrow(negative: bool, wide: Self::Wide) -> Option<Self> {
+                if negative && !num_traits::Zero::is_zero(&wide) {
+                    None
+                } else {
+                    <$tty>::try_from(wide).ok()
+                }
+            }
+
+            fn narrow_wrapping(negative: bool, wide: Self::Wide) -> Self {
+                let value: $tty = Truncate::truncate(wide);
+                if negative {
+                    value.wrapping_neg()
+                } else {
+                    value
+                }
+            }
+        }
+
         impl ILog10 for $tty {
             fn ilog10(self) -> u32 {
                 self.ilog10()
@@ -119,16 +245,16 @@ macro_rules! unsign_impl {
     };
 }
 
-sign_impl!(i128, u128);
-sign_impl!(i64, u64);
-sign_impl!(i32, u32);
-sign_impl!(i16, u16);
-sign_impl!(i8, u8);
-unsign_impl!(u128);
-unsign_impl!(u64);
-unsign_impl!(u32);
-unsign_impl!(u16);
-unsign_impl!(u8);
+sign_impl!(i128, u128, U256);
+sign_impl!(i64, u64, u128);
+sign_impl!(i32, u32, u64);
+sign_impl!(i16, u16, u32);
+sign_impl!(i8, u8, u16);
+unsign_impl!(u128, U256);
+unsign_impl!(u64, u128);
+unsign_impl!(u32, u64);
+unsign_impl!(u16, u32);
+unsign_impl!(u8, u16);
 
 impl<T: ConstTen> Ten for T {
     fn ten() -> Self {
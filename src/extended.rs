@@ -0,0 +1,411 @@
+use std::{cmp::Ordering, sync::OnceLock};
+
+use crate::{bignum::BigUint, ext_num_traits, rounding, FixedDecimal, RoundingStrategy};
+
+/// Significant digits kept by every [`Extended`] operation.
+const PRECISION: u32 = 80;
+
+/// Significant digits trusted when rounding an [`Extended`] back to a
+/// [`FixedDecimal`], the remaining ones absorb the error of the operations.
+const TRUSTED: u32 = 60;
+
+/// Arguments of `exp` beyond this bound overflow or vanish for any backing
+/// type and scale.
+const EXP_BOUND: u128 = 2000;
+
+/// Extended precision decimal float `±mantissa * 10^exponent`, used as the
+/// intermediate of operations that can not be computed exactly, such as
+/// powers and logarithms.
+#[derive(Clone, Debug)]
+pub(crate) struct Extended {
+    negative: bool,
+    mantissa: BigUint,
+    exponent: i64,
+}
+
+impl Extended {
+    /// Truncates the mantissa to `PRECISION` significant digits.
+    fn new(negative: bool, mantissa: BigUint, exponent: i64) -> Self {
+        if mantissa.is_zero() {
+            return Self::zero();
+        }
+        let digits = mantissa.digits();
+        if digits <= PRECISION {
+            return Self {
+                negative,
+                mantissa,
+                exponent,
+            };
+        }
+        let dropped = digits - PRECISION;
+        let (mantissa, _) = mantissa.divrem(&BigUint::pow10(dropped));
+        Self {
+            negative,
+            mantissa,
+            exponent: exponent + i64::from(dropped),
+        }
+    }
+
+    /// Rounds the mantissa to nearest with `precision` significant digits.
+    fn rounded(&self, precision: u32) -> Self {
+        let digits = self.mantissa.digits();
+        if digits <= precision {
+            return self.clone();
+        }
+        let dropped = digits - precision;
+        let divisor = BigUint::pow10(dropped);
+        let (mut mantissa, remainder) = self.mantissa.divrem(&divisor);
+        if remainder.shl(1) >= divisor {
+            mantissa = mantissa.add(&BigUint::from_u128(1));
+        }
+        Self::new(self.negative, mantissa, self.exponent + i64::from(dropped))
+    }
+
+    pub(crate) fn zero() -> Self {
+        Self {
+            negative: false,
+            mantissa: BigUint::zero(),
+            exponent: 0,
+        }
+    }
+
+    pub(crate) fn from_u128(n: u128) -> Self {
+        Self::new(false, BigUint::from_u128(n), 0)
+    }
+
+    fn one() -> Self {
+        Self::from_u128(1)
+    }
+
+    /// A positive value smaller than half an unit of any scale, standing for
+    /// results that vanish but are not exactly zero.
+    fn tiny() -> Self {
+        Self::new(false, BigUint::from_u128(1), -(4 * EXP_BOUND as i64))
+    }
+
+    pub(crate) fn from_fixed<T: ext_num_traits::Widen, const E: u8>(
+        value: FixedDecimal<T, E>,
+    ) -> Self {
+        let magnitude = value.0.widen().try_into().ok();
+        let magnitude = magnitude.expect("backing magnitudes fit u128");
+        Self::new(
+            !value.0.is_positive(),
+            BigUint::from_u128(magnitude),
+            -i64::from(E),
+        )
+    }
+
+    pub(crate) fn is_zero(&self) -> bool {
+        self.mantissa.is_zero()
+    }
+
+    pub(crate) fn neg(&self) -> Self {
+        Self {
+            negative: !self.negative && !self.is_zero(),
+            ..self.clone()
+        }
+    }
+
+    /// Exponent of the digit right above the most significant one, i.e. the
+    /// value is smaller than `10^top` and at least `10^(top - 1)`.
+    fn top(&self) -> i64 {
+        self.exponent + i64::from(self.mantissa.digits())
+    }
+
+    pub(crate) fn mul(&self, rhs: &Self) -> Self {
+        Self::new(
+            self.negative != rhs.negative,
+            self.mantissa.mul(&rhs.mantissa),
+            self.exponent + rhs.exponent,
+        )
+    }
+
+    /// `None` if `rhs` is zero.
+    pub(crate) fn div(&self, rhs: &Self) -> Option<Self> {
+        if rhs.is_zero() {
+            return None;
+        }
+        // Scale the dividend so the quotient has at least `PRECISION` digits.
+        let shift = (i64::from(PRECISION) + i64::from(rhs.mantissa.digits())
+            - i64::from(self.mantissa.digits())
+            + 1)
+        .max(0);
+        let dividend = self.mantissa.mul(&BigUint::pow10(shift as u32));
+        let (quotient, _) = dividend.divrem(&rhs.mantissa);
+        Some(Self::new(
+            self.negative != rhs.negative,
+            quotient,
+            self.exponent - rhs.exponent - shift,
+        ))
+    }
+
+    pub(crate) fn add(&self, rhs: &Self) -> Self {
+        if self.is_zero() {
+            return rhs.clone();
+        }
+        if rhs.is_zero() {
+            return self.clone();
+        }
+        // An operand entirely below the precision of the other one is lost
+        // by the truncation anyway.
+        let gap = i64::from(PRECISION) + 2;
+        if self.top() - rhs.top() > gap {
+            return self.clone();
+        }
+        if rhs.top() - self.top() > gap {
+            return rhs.clone();
+        }
+        let exponent = self.exponent.min(rhs.exponent);
+        let align = |x: &Self| {
+            x.mantissa
+                .mul(&BigUint::pow10((x.exponent - exponent) as u32))
+        };
+        let (a, b) = (align(self), align(rhs));
+        if self.negative == rhs.negative {
+            return Self::new(self.negative, a.add(&b), exponent);
+        }
+        match a.cmp(&b) {
+            Ordering::Less => Self::new(rhs.negative, b.sub(&a), exponent),
+            Ordering::Equal => Self::zero(),
+            Ordering::Greater => Self::new(self.negative, a.sub(&b), exponent),
+        }
+    }
+
+    pub(crate) fn sub(&self, rhs: &Self) -> Self {
+        self.add(&rhs.neg())
+    }
+
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        let diff = self.sub(rhs);
+        if diff.is_zero() {
+            Ordering::Equal
+        } else if diff.negative {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        }
+    }
+
+    /// `self^n`, or `None` if the result overflows any backing type.
+    ///
+    /// Results too small for any scale are replaced by [`Extended::tiny`].
+    pub(crate) fn powi(&self, mut n: u64) -> Option<Self> {
+        let negative = self.negative && n & 1 == 1;
+        let mut base = Self {
+            negative: false,
+            ..self.clone()
+        };
+        let mut result = Self::one();
+        let bound = 4 * EXP_BOUND as i64;
+        while n > 0 {
+            if n & 1 == 1 {
+                result = result.mul(&base);
+            }
+            n >>= 1;
+            if n > 0 {
+                base = base.mul(&base);
+            }
+            if result.top() > bound || (base.top() > bound && n > 0) {
+                return None;
+            }
+            if result.top() < -bound || (base.top() < -bound && n > 0) {
+                result = Self::tiny();
+                break;
+            }
+        }
+        result.negative = negative && !result.is_zero();
+        Some(result)
+    }
+
+    /// Rounds to an integer toward zero, or `None` if it does not fit.
+    fn to_i64(&self) -> Option<i64> {
+        let magnitude = if self.exponent >= 0 {
+            if self.top() > 19 {
+                return None;
+            }
+            self.mantissa.mul(&BigUint::pow10(self.exponent as u32))
+        } else if self.top() < 0 {
+            BigUint::zero()
+        } else {
+            self.mantissa
+                .divrem(&BigUint::pow10(-self.exponent as u32))
+                .0
+        };
+        let magnitude = i64::try_from(magnitude.to_u128()?).ok()?;
+        Some(if self.negative { -magnitude } else { magnitude })
+    }
+
+    /// Sum of the series `term(1) + term(2) + ...` up to the precision of the
+    /// sum, `term` getting the previous term and the index of the new one.
+    fn series(first: Self, mut term: impl FnMut(&Self, u64) -> Self) -> Self {
+        let (mut sum, mut last) = (first.clone(), first);
+        for i in 1.. {
+            last = term(&last, i);
+            if last.is_zero() || last.top() < sum.top() - i64::from(PRECISION) - 2 {
+                break;
+            }
+            sum = sum.add(&last);
+        }
+        sum
+    }
+
+    /// `atanh(z) = z + z^3/3 + z^5/5 + ...` for small `|z|`.
+    fn atanh(z: &Self) -> Self {
+        let z2 = z.mul(z);
+        let mut power = z.clone();
+        Self::series(z.clone(), |_, i| {
+            power = power.mul(&z2);
+            power
+                .div(&Self::from_u128(2 * u128::from(i) + 1))
+                .expect("odd divisor")
+        })
+    }
+
+    fn ln2() -> Self {
+        static LN2: OnceLock<Extended> = OnceLock::new();
+        LN2.get_or_init(|| {
+            let third = Self::one().div(&Self::from_u128(3)).expect("non zero");
+            Self::atanh(&third).mul(&Self::from_u128(2))
+        })
+        .clone()
+    }
+
+    pub(crate) fn ln10() -> Self {
+        static LN10: OnceLock<Extended> = OnceLock::new();
+        LN10.get_or_init(|| {
+            // `ln(10) = 3 * ln(2) + ln(1.25)` and `ln(1.25) = 2 * atanh(1/9)`.
+            let ninth = Self::one().div(&Self::from_u128(9)).expect("non zero");
+            let ln_5_4 = Self::atanh(&ninth).mul(&Self::from_u128(2));
+            Self::ln2().mul(&Self::from_u128(3)).add(&ln_5_4)
+        })
+        .clone()
+    }
+
+    /// Natural logarithm, or `None` if `self` is not positive.
+    pub(crate) fn ln(&self) -> Option<Self> {
+        if self.negative || self.is_zero() {
+            return None;
+        }
+        let one = Self::one();
+        if self.cmp(&one) == Ordering::Equal {
+            return Some(Self::zero());
+        }
+        // `x = a * 10^k` keeping `x` as is when it is near one, so its
+        // logarithm does not come out of a cancellation.
+        let mut k = 0;
+        let mut a = self.clone();
+        if !(0..=1).contains(&self.top()) {
+            k = self.top() - 1;
+            a.exponent -= k;
+        }
+        // `a = b * 2^j` with `b` in `[0.75, 1.5]`.
+        let (half, two) = (
+            Self::new(false, BigUint::from_u128(5), -1),
+            Self::from_u128(2),
+        );
+        let (low, high) = (
+            Self::new(false, BigUint::from_u128(75), -2),
+            Self::new(false, BigUint::from_u128(15), -1),
+        );
+        let mut j = 0i64;
+        while a.cmp(&high) == Ordering::Greater {
+            a = a.mul(&half);
+            j += 1;
+        }
+        while a.cmp(&low) == Ordering::Less {
+            a = a.mul(&two);
+            j -= 1;
+        }
+        // `ln(b) = 2 * atanh((b - 1) / (b + 1))`.
+        let z = a.sub(&one).div(&a.add(&one)).expect("b is positive");
+        let mut result = Self::atanh(&z).mul(&two);
+        if j != 0 {
+            let j = Self::new(j < 0, BigUint::from_u128(j.unsigned_abs().into()), 0);
+            result = result.add(&Self::ln2().mul(&j));
+        }
+        if k != 0 {
+            let k = Self::new(k < 0, BigUint::from_u128(k.unsigned_abs().into()), 0);
+            result = result.add(&Self::ln10().mul(&k));
+        }
+        Some(result)
+    }
+
+    /// Natural exponential, or `None` if it overflows any backing type.
+    pub(crate) fn exp(&self) -> Option<Self> {
+        if self.is_zero() {
+            return Some(Self::one());
+        }
+        let bound = Self::from_u128(EXP_BOUND);
+        if self.cmp(&bound) == Ordering::Greater {
+            return None;
+        }
+        if self.cmp(&bound.neg()) == Ordering::Less {
+            return Some(Self::tiny());
+        }
+        // `x = k * ln(2) + r` with `|r| <= ln(2) / 2`, then `exp(r)` is
+        // computed as `exp(r / 2^8)^(2^8)`.
+        let ln2 = Self::ln2();
+        let half = Self::new(self.negative, BigUint::from_u128(5), -1);
+        let k = self.div(&ln2)?.add(&half).to_i64()?;
+        let k_ext = Self::new(k < 0, BigUint::from_u128(k.unsigned_abs().into()), 0);
+        let r = self.sub(&ln2.mul(&k_ext));
+
+        const SQUARINGS: u32 = 8;
+        let r = r.mul(&Self::new(
+            false,
+            BigUint::from_u128(5).pow(SQUARINGS.into()),
+            -i64::from(SQUARINGS),
+        ));
+        let mut result = Self::series(Self::one(), |last, i| {
+            last.mul(&r)
+                .div(&Self::from_u128(i.into()))
+                .expect("non zero index")
+        });
+        for _ in 0..SQUARINGS {
+            result = result.mul(&result);
+        }
+
+        // `2^-k = 5^k * 10^-k` keeps the scaling exact.
+        let scale = if k >= 0 {
+            Self::new(false, BigUint::from_u128(2).pow(k.unsigned_abs()), 0)
+        } else {
+            Self::new(false, BigUint::from_u128(5).pow(k.unsigned_abs()), k)
+        };
+        Some(result.mul(&scale))
+    }
+
+    /// Rounds to the scale `E` with `strategy`, or `None` on overflow.
+    pub(crate) fn to_fixed<T: ext_num_traits::Widen, const E: u8>(
+        &self,
+        strategy: RoundingStrategy,
+    ) -> Option<FixedDecimal<T, E>> {
+        let Self {
+            negative,
+            mantissa,
+            exponent,
+        } = self.rounded(TRUSTED);
+        let target = -i64::from(E);
+        // No backing type holds more than 39 digits.
+        if exponent + i64::from(mantissa.digits()) - target > 39 {
+            return None;
+        }
+        let (quotient, remainder) = if exponent >= target {
+            let scaled = mantissa.mul(&BigUint::pow10((exponent - target) as u32));
+            (scaled, None)
+        } else if target - exponent > i64::from(mantissa.digits()) {
+            (
+                BigUint::zero(),
+                (!mantissa.is_zero()).then_some(Ordering::Less),
+            )
+        } else {
+            let divisor = BigUint::pow10((target - exponent) as u32);
+            let (quotient, remainder) = mantissa.divrem(&divisor);
+            let half = (!remainder.is_zero()).then(|| remainder.shl(1).cmp(&divisor));
+            (quotient, half)
+        };
+        let quotient = T::Wide::try_from(quotient.to_u128()?).ok()?;
+        rounding::round_quotient(quotient, remainder, negative, strategy)
+            .and_then(|rounded| T::narrow(negative, rounded))
+            .map(FixedDecimal)
+    }
+}
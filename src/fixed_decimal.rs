@@ -1,7 +1,13 @@
 use core::fmt;
-use std::{ops, str::FromStr};
+use num_traits::Zero;
+use std::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+    ops,
+    str::FromStr,
+};
 
-use crate::ext_num_traits;
+use crate::{bignum::BigUint, ext_num_traits, rounding, ArithmeticError, RoundingStrategy};
 
 pub struct FixedDecimal<T, const SCALE: u8>(pub(crate) T);
 
@@ -78,20 +84,78 @@ impl<T: Clone, const E: u8> Clone for FixedDecimal<T, E> {
     }
 }
 
-impl<T: Eq, const E: u8> Eq for FixedDecimal<T, E> {}
-impl<T: PartialEq, const E: u8> PartialEq for FixedDecimal<T, E> {
-    fn eq(&self, other: &Self) -> bool {
-        self.0.eq(&other.0)
+impl<T: ext_num_traits::Widen + Eq, const E: u8> Eq for FixedDecimal<T, E> {}
+
+/// Compares numeric values, so decimals of different scales are equal when
+/// they represent the same number, e.g. `1.5 == 1.50`.
+///
+/// The scale of `other` is not inferred from `self`, a right hand side such
+/// as `FixedDecimalI64::new(15)` needs its scale spelled out.
+impl<T: ext_num_traits::Widen, const E1: u8, const E2: u8> PartialEq<FixedDecimal<T, E2>>
+    for FixedDecimal<T, E1>
+{
+    fn eq(&self, other: &FixedDecimal<T, E2>) -> bool {
+        self.cmp_value(other) == Ordering::Equal
     }
 }
-impl<T: Ord, const E: u8> Ord for FixedDecimal<T, E> {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+
+impl<T: ext_num_traits::Widen + Ord, const E: u8> Ord for FixedDecimal<T, E> {
+    fn cmp(&self, other: &Self) -> Ordering {
         self.0.cmp(&other.0)
     }
 }
-impl<T: PartialOrd, const E: u8> PartialOrd for FixedDecimal<T, E> {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.0.partial_cmp(&other.0)
+
+/// Compares numeric values, so decimals of different scales are ordered by
+/// the number they represent, e.g. `1.5 < 1.51`. As for [`PartialEq`], the
+/// scale of `other` is not inferred from `self`.
+impl<T: ext_num_traits::Widen, const E1: u8, const E2: u8> PartialOrd<FixedDecimal<T, E2>>
+    for FixedDecimal<T, E1>
+{
+    fn partial_cmp(&self, other: &FixedDecimal<T, E2>) -> Option<Ordering> {
+        Some(self.cmp_value(other))
+    }
+}
+
+/// Hashes the numeric value, so it is consistent with the cross scale
+/// [`PartialEq`]: `1.5` and `1.50` hash the same.
+impl<T: ext_num_traits::Widen, const E: u8> Hash for FixedDecimal<T, E> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let ten = <T::Wide as ext_num_traits::ConstTen>::TEN;
+        let mut magnitude = self.0.widen();
+        let mut exponent = -i16::from(E);
+        if magnitude.is_zero() {
+            exponent = 0;
+        }
+        while !magnitude.is_zero() && (magnitude % ten).is_zero() {
+            magnitude = magnitude / ten;
+            exponent += 1;
+        }
+        self.0.is_positive().hash(state);
+        magnitude.hash(state);
+        exponent.hash(state);
+    }
+}
+
+#[expect(private_bounds)]
+impl<T: ext_num_traits::Widen, const E: u8> FixedDecimal<T, E> {
+    fn cmp_value<const E2: u8>(&self, other: &FixedDecimal<T, E2>) -> Ordering {
+        let (negative, other_negative) = (!self.0.is_positive(), !other.0.is_positive());
+        if negative != other_negative {
+            return other_negative.cmp(&negative);
+        }
+        // Bring both magnitudes to the biggest scale, a magnitude that
+        // overflows while scaling is bigger than any other one.
+        let (a, b) = (self.0.widen(), other.0.widen());
+        let magnitudes = if E >= E2 {
+            rounding::mul_pow10(b, u32::from(E - E2)).map_or(Ordering::Less, |b| a.cmp(&b))
+        } else {
+            rounding::mul_pow10(a, u32::from(E2 - E)).map_or(Ordering::Greater, |a| a.cmp(&b))
+        };
+        if negative {
+            magnitudes.reverse()
+        } else {
+            magnitudes
+        }
     }
 }
 
@@ -155,6 +219,10 @@ where
     }
 }
 
+/// Adds the mantissas with the backing integer `+`, so overflow behaves as
+/// for the integer: it panics when debug assertions are enabled and wraps
+/// otherwise. See [`FixedDecimal::wrapping_add`], [`FixedDecimal::overflowing_add`]
+/// and [`num_traits::CheckedAdd`] for explicit behaviors.
 impl<T: ops::Add<Output = T>, const E: u8> ops::Add for FixedDecimal<T, E> {
     type Output = Self;
 
@@ -175,8 +243,8 @@ impl<'a, T: ops::AddAssign + Copy, const E: u8> ops::AddAssign<&'a FixedDecimal<
         Self::add_assign(self, *rhs)
     }
 }
-impl<'a, T: ops::AddAssign, const E: u8> ops::AddAssign<FixedDecimal<T, E>>
-    for &'a mut FixedDecimal<T, E>
+impl<T: ops::AddAssign, const E: u8> ops::AddAssign<FixedDecimal<T, E>>
+    for &mut FixedDecimal<T, E>
 {
     fn add_assign(&mut self, rhs: FixedDecimal<T, E>) {
         self.0.add_assign(rhs.0)
@@ -197,6 +265,10 @@ impl<T: num_traits::CheckedAdd, const E: u8> num_traits::CheckedAdd for FixedDec
     }
 }
 
+/// Subtracts the mantissas with the backing integer `-`, so overflow behaves
+/// as for the integer: it panics when debug assertions are enabled and wraps
+/// otherwise. See [`FixedDecimal::wrapping_sub`], [`FixedDecimal::overflowing_sub`]
+/// and [`num_traits::CheckedSub`] for explicit behaviors.
 impl<T: ops::Sub<Output = T>, const E: u8> ops::Sub for FixedDecimal<T, E> {
     type Output = Self;
 
@@ -204,6 +276,12 @@ impl<T: ops::Sub<Output = T>, const E: u8> ops::Sub for FixedDecimal<T, E> {
         Self(self.0.sub(rhs.0))
     }
 }
+
+impl<T: num_traits::CheckedSub, const E: u8> num_traits::CheckedSub for FixedDecimal<T, E> {
+    fn checked_sub(&self, v: &Self) -> Option<Self> {
+        self.0.checked_sub(&v.0).map(Self)
+    }
+}
 impl<T: num_traits::ConstZero, const E: u8> num_traits::ConstZero for FixedDecimal<T, E> {
     const ZERO: Self = Self(T::ZERO);
 }
@@ -218,6 +296,82 @@ impl<T: num_traits::Zero, const E: u8> num_traits::Zero for FixedDecimal<T, E> {
     }
 }
 
+impl<T: ext_num_traits::ConstTenPow<E>, const E: u8> num_traits::ConstOne for FixedDecimal<T, E>
+where
+    Self: num_traits::One,
+{
+    const ONE: Self = Self(T::RESULT);
+}
+
+impl<T, const E: u8> num_traits::One for FixedDecimal<T, E>
+where
+    T: ext_num_traits::Widen + ext_num_traits::ConstTenPow<E>,
+{
+    fn one() -> Self {
+        Self::ONE
+    }
+}
+
+/// Only radix 10 is supported, any other radix fails with
+/// `ParseError::InvalidRadix`.
+impl<T, const E: u8> num_traits::Num for FixedDecimal<T, E>
+where
+    T: num_traits::Num + ext_num_traits::Widen + ext_num_traits::ConstTenPow<E>,
+    Self: FromStr<Err = crate::str::ParseError>,
+{
+    type FromStrRadixErr = crate::str::ParseError;
+
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        if radix != 10 {
+            return Err(crate::str::ParseError::InvalidRadix);
+        }
+        Self::from_str(str)
+    }
+}
+
+impl<T, const E: u8> num_traits::Signed for FixedDecimal<T, E>
+where
+    T: num_traits::Signed + ext_num_traits::Widen + ext_num_traits::ConstTenPow<E>,
+    Self: num_traits::Num,
+{
+    fn abs(&self) -> Self {
+        Self(self.0.abs())
+    }
+
+    fn abs_sub(&self, other: &Self) -> Self {
+        Self(self.0.abs_sub(&other.0))
+    }
+
+    fn signum(&self) -> Self {
+        Self(self.0.signum() * T::RESULT)
+    }
+
+    fn is_positive(&self) -> bool {
+        num_traits::Signed::is_positive(&self.0)
+    }
+
+    fn is_negative(&self) -> bool {
+        self.0.is_negative()
+    }
+}
+
+impl<T, const E: u8> num_traits::Unsigned for FixedDecimal<T, E>
+where
+    T: num_traits::Unsigned + ext_num_traits::Widen + ext_num_traits::ConstTenPow<E>,
+    Self: num_traits::Num,
+{
+}
+
+impl<T: ext_num_traits::ConstBound, const E: u8> num_traits::Bounded for FixedDecimal<T, E> {
+    fn min_value() -> Self {
+        Self::MIN
+    }
+
+    fn max_value() -> Self {
+        Self::MAX
+    }
+}
+
 impl<T: ops::Neg<Output = T>, const E: u8> ops::Neg for FixedDecimal<T, E> {
     type Output = Self;
 
@@ -226,6 +380,25 @@ impl<T: ops::Neg<Output = T>, const E: u8> ops::Neg for FixedDecimal<T, E> {
     }
 }
 
+impl<T: ops::Neg<Output = T> + Copy, const E: u8> ops::Neg for &FixedDecimal<T, E> {
+    type Output = FixedDecimal<T, E>;
+
+    fn neg(self) -> Self::Output {
+        -*self
+    }
+}
+
+impl<T: num_traits::CheckedNeg, const E: u8> num_traits::CheckedNeg for FixedDecimal<T, E> {
+    fn checked_neg(&self) -> Option<Self> {
+        self.0.checked_neg().map(Self)
+    }
+}
+
+/// Multiplies the mantissa by an integer with the backing integer `*`, so
+/// overflow behaves as for the integer: it panics when debug assertions are
+/// enabled and wraps otherwise. See [`FixedDecimal::wrapping_mul_int`],
+/// [`FixedDecimal::overflowing_mul_int`] and [`FixedDecimal::checked_mul_int`]
+/// for explicit behaviors.
 impl<T: ops::Mul<Output = T>, const E: u8> ops::Mul<T> for FixedDecimal<T, E> {
     type Output = Self;
 
@@ -234,6 +407,1148 @@ impl<T: ops::Mul<Output = T>, const E: u8> ops::Mul<T> for FixedDecimal<T, E> {
     }
 }
 
+impl<T: num_traits::CheckedMul, const E: u8> FixedDecimal<T, E> {
+    /// Checked multiplication by an integer.
+    /// Returns `None` if the result overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI8;
+    /// #
+    /// let a = FixedDecimalI8::<1>::new(15);
+    /// assert_eq!(a.checked_mul_int(3).unwrap().to_string(), "4.5");
+    /// assert_eq!(a.checked_mul_int(10), None);
+    /// ```
+    #[must_use]
+    pub fn checked_mul_int(self, rhs: T) -> Option<Self> {
+        self.0.checked_mul(&rhs).map(Self)
+    }
+}
+
+/// Multiplies two decimals of the same scale, rounding the result with
+/// [`RoundingStrategy::MidpointNearestEven`].
+///
+/// # Panics
+///
+/// Panics if the result overflows, see [`FixedDecimal::checked_mul`].
+impl<T: ext_num_traits::Widen, const E: u8> ops::Mul for FixedDecimal<T, E> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.mul_with_rounding(rhs, RoundingStrategy::default())
+    }
+}
+
+impl<T: ext_num_traits::Widen, const E: u8> num_traits::CheckedMul for FixedDecimal<T, E> {
+    fn checked_mul(&self, v: &Self) -> Option<Self> {
+        Self::checked_mul(*self, *v)
+    }
+}
+
+/// Divides two decimals of the same scale, rounding the result with
+/// [`RoundingStrategy::MidpointNearestEven`].
+///
+/// # Panics
+///
+/// Panics if `rhs` is zero or the result overflows, see
+/// [`FixedDecimal::checked_div`].
+impl<T: ext_num_traits::Widen, const E: u8> ops::Div for FixedDecimal<T, E> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.div_with_rounding(rhs, RoundingStrategy::default())
+    }
+}
+
+impl<T: ext_num_traits::Widen, const E: u8> num_traits::CheckedDiv for FixedDecimal<T, E> {
+    fn checked_div(&self, v: &Self) -> Option<Self> {
+        Self::checked_div(*self, *v)
+    }
+}
+
+/// Fused `self * a + b` rounding once, see [`FixedDecimal::mul_add`].
+///
+/// # Panics
+///
+/// Panics if the result overflows.
+impl<T: ext_num_traits::Widen, const E: u8> num_traits::MulAdd for FixedDecimal<T, E> {
+    type Output = Self;
+
+    fn mul_add(self, a: Self, b: Self) -> Self::Output {
+        Self::mul_add(self, a, b)
+    }
+}
+
+/// Remainder of the truncated division, with the same sign as `self`.
+///
+/// # Panics
+///
+/// Panics if `rhs` is zero or the division overflows.
+impl<T: ops::Rem<Output = T>, const E: u8> ops::Rem for FixedDecimal<T, E> {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        Self(self.0.rem(rhs.0))
+    }
+}
+
+impl<T: num_traits::CheckedRem, const E: u8> num_traits::CheckedRem for FixedDecimal<T, E> {
+    fn checked_rem(&self, v: &Self) -> Option<Self> {
+        self.0.checked_rem(&v.0).map(Self)
+    }
+}
+
+/// Borrowed forms of a binary operator, copying the operands into the owned
+/// implementation so they share its rounding and overflow behavior.
+macro_rules! forward_ref_binop {
+    ($($imp:ident, $method:ident);+) => {$(
+        impl<T: Copy, const E: u8> ops::$imp<FixedDecimal<T, E>> for &FixedDecimal<T, E>
+        where
+            FixedDecimal<T, E>: ops::$imp<Output = FixedDecimal<T, E>>,
+        {
+            type Output = FixedDecimal<T, E>;
+
+            fn $method(self, rhs: FixedDecimal<T, E>) -> Self::Output {
+                ops::$imp::$method(*self, rhs)
+            }
+        }
+
+        impl<T: Copy, const E: u8> ops::$imp<&FixedDecimal<T, E>> for FixedDecimal<T, E>
+        where
+            FixedDecimal<T, E>: ops::$imp<Output = FixedDecimal<T, E>>,
+        {
+            type Output = FixedDecimal<T, E>;
+
+            fn $method(self, rhs: &FixedDecimal<T, E>) -> Self::Output {
+                ops::$imp::$method(self, *rhs)
+            }
+        }
+
+        impl<T: Copy, const E: u8> ops::$imp<&FixedDecimal<T, E>> for &FixedDecimal<T, E>
+        where
+            FixedDecimal<T, E>: ops::$imp<Output = FixedDecimal<T, E>>,
+        {
+            type Output = FixedDecimal<T, E>;
+
+            fn $method(self, rhs: &FixedDecimal<T, E>) -> Self::Output {
+                ops::$imp::$method(*self, *rhs)
+            }
+        }
+    )+};
+}
+
+forward_ref_binop!(Add, add; Sub, sub; Mul, mul; Div, div; Rem, rem);
+
+/// Assign forms of a binary operator with an owned or borrowed right hand
+/// side, with the same rounding and overflow behavior as the operator.
+macro_rules! assign_binop {
+    ($($imp:ident, $method:ident, $op:ident, $op_method:ident);+) => {$(
+        impl<T: Copy, const E: u8> ops::$imp for FixedDecimal<T, E>
+        where
+            FixedDecimal<T, E>: ops::$op<Output = FixedDecimal<T, E>>,
+        {
+            fn $method(&mut self, rhs: Self) {
+                *self = ops::$op::$op_method(*self, rhs);
+            }
+        }
+
+        impl<T: Copy, const E: u8> ops::$imp<&FixedDecimal<T, E>> for FixedDecimal<T, E>
+        where
+            FixedDecimal<T, E>: ops::$op<Output = FixedDecimal<T, E>>,
+        {
+            fn $method(&mut self, rhs: &Self) {
+                *self = ops::$op::$op_method(*self, *rhs);
+            }
+        }
+
+        impl<T: Copy, const E: u8> ops::$imp<FixedDecimal<T, E>> for &mut FixedDecimal<T, E>
+        where
+            FixedDecimal<T, E>: ops::$op<Output = FixedDecimal<T, E>>,
+        {
+            fn $method(&mut self, rhs: FixedDecimal<T, E>) {
+                **self = ops::$op::$op_method(**self, rhs);
+            }
+        }
+
+        impl<'a, T: Copy, const E: u8> ops::$imp<&'a FixedDecimal<T, E>>
+            for &'a mut FixedDecimal<T, E>
+        where
+            FixedDecimal<T, E>: ops::$op<Output = FixedDecimal<T, E>>,
+        {
+            fn $method(&mut self, rhs: &'a FixedDecimal<T, E>) {
+                **self = ops::$op::$op_method(**self, *rhs);
+            }
+        }
+    )+};
+}
+
+assign_binop!(
+    SubAssign, sub_assign, Sub, sub;
+    MulAssign, mul_assign, Mul, mul;
+    DivAssign, div_assign, Div, div;
+    RemAssign, rem_assign, Rem, rem
+);
+
+impl<T: ops::Div<Output = T> + ops::Rem<Output = T> + Copy, const E: u8> FixedDecimal<T, E> {
+    /// Returns how many whole `rhs` fit into `self` (truncated toward zero)
+    /// and what is left over, in the same scale.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero or the division overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI64;
+    /// # use std::str::FromStr;
+    /// #
+    /// let balance = FixedDecimalI64::<2>::from_str("10.50").unwrap();
+    /// let lot = FixedDecimalI64::<2>::from_str("3.00").unwrap();
+    /// let (lots, left) = balance.div_rem(lot);
+    /// assert_eq!(lots, 3);
+    /// assert_eq!(left.to_string(), "1.50");
+    /// ```
+    #[must_use]
+    pub fn div_rem(self, rhs: Self) -> (T, Self) {
+        (self.0 / rhs.0, Self(self.0 % rhs.0))
+    }
+}
+
+impl<T: num_traits::Euclid, const E: u8> FixedDecimal<T, E> {
+    /// Euclidean division, the count of whole `rhs` such that the
+    /// remainder is non negative, see [`FixedDecimal::rem_euclid`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero or the division overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI64;
+    /// # use std::str::FromStr;
+    /// #
+    /// let a = FixedDecimalI64::<2>::from_str("-7.50").unwrap();
+    /// let b = FixedDecimalI64::<2>::from_str("2").unwrap();
+    /// assert_eq!(a.div_euclid(b), -4);
+    /// assert_eq!((-a).div_euclid(b), 3);
+    /// ```
+    #[must_use]
+    pub fn div_euclid(self, rhs: Self) -> T {
+        num_traits::Euclid::div_euclid(&self.0, &rhs.0)
+    }
+
+    /// Least non negative remainder of `self (mod rhs)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero or the division overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI64;
+    /// # use std::str::FromStr;
+    /// #
+    /// let a = FixedDecimalI64::<2>::from_str("-7.50").unwrap();
+    /// let b = FixedDecimalI64::<2>::from_str("2").unwrap();
+    /// assert_eq!(a.rem_euclid(b).to_string(), "0.50");
+    /// assert_eq!((a % b).to_string(), "-1.50");
+    /// ```
+    #[must_use]
+    pub fn rem_euclid(self, rhs: Self) -> Self {
+        Self(num_traits::Euclid::rem_euclid(&self.0, &rhs.0))
+    }
+}
+
+#[expect(private_bounds)]
+impl<T: ext_num_traits::Widen, const E: u8> FixedDecimal<T, E> {
+    /// Multiplies two decimals rounding the full product back to `E` with the
+    /// given strategy.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::{FixedDecimalI64, RoundingStrategy};
+    /// # use std::str::FromStr;
+    /// #
+    /// let price = FixedDecimalI64::<2>::from_str("10.05").unwrap();
+    /// let qty = FixedDecimalI64::<2>::from_str("0.50").unwrap();
+    /// let total = price.mul_with_rounding(qty, RoundingStrategy::ToZero);
+    /// assert_eq!(total.to_string(), "5.02");
+    /// ```
+    #[must_use]
+    pub fn mul_with_rounding(self, rhs: Self, strategy: RoundingStrategy) -> Self {
+        self.checked_mul_with_rounding(rhs, strategy)
+            .expect("attempt to multiply with overflow")
+    }
+
+    /// Checked decimal multiplication, rounding with
+    /// [`RoundingStrategy::MidpointNearestEven`].
+    /// Returns `None` if the result overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI8;
+    /// #
+    /// let a = FixedDecimalI8::<1>::new(15);
+    /// assert_eq!(a.checked_mul(a).unwrap().to_string(), "2.2");
+    /// assert_eq!(a.checked_mul(FixedDecimalI8::<1>::MAX), None);
+    /// ```
+    #[must_use]
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        self.checked_mul_with_rounding(rhs, RoundingStrategy::default())
+    }
+
+    /// Checked decimal multiplication, rounding the full product back to `E`
+    /// with the given strategy.
+    /// Returns `None` if the result overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::{FixedDecimalI8, RoundingStrategy};
+    /// #
+    /// let a = FixedDecimalI8::<1>::new(-15);
+    /// let b = FixedDecimalI8::<1>::new(15);
+    /// let floor = a.checked_mul_with_rounding(b, RoundingStrategy::ToNegativeInfinity);
+    /// assert_eq!(floor.unwrap().to_string(), "-2.3");
+    /// ```
+    #[must_use]
+    pub fn checked_mul_with_rounding(self, rhs: Self, strategy: RoundingStrategy) -> Option<Self> {
+        let negative = self.0.is_positive() != rhs.0.is_positive();
+        let product = num_traits::CheckedMul::checked_mul(&self.0.widen(), &rhs.0.widen())?;
+        let rounded = rounding::div_pow10_rounded(product, E.into(), negative, strategy)?;
+        T::narrow(negative, rounded).map(Self)
+    }
+
+    /// Multiplies decimals of different scales into a caller chosen result
+    /// scale `R`.
+    ///
+    /// The result is exact when `R >= E + E2`, otherwise the product is
+    /// rounded with the given strategy.
+    /// Returns `None` if the result overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::{FixedDecimalI64, RoundingStrategy};
+    /// # use std::str::FromStr;
+    /// #
+    /// let price = FixedDecimalI64::<2>::from_str("10.05").unwrap();
+    /// let qty = FixedDecimalI64::<8>::from_str("0.12345678").unwrap();
+    ///
+    /// let exact: FixedDecimalI64<10> = price.mul_into(qty, RoundingStrategy::default()).unwrap();
+    /// assert_eq!(exact.to_string(), "1.2407406390");
+    ///
+    /// let total: FixedDecimalI64<2> = price.mul_into(qty, RoundingStrategy::ToZero).unwrap();
+    /// assert_eq!(total.to_string(), "1.24");
+    /// ```
+    #[must_use]
+    pub fn mul_into<const E2: u8, const R: u8>(
+        self,
+        rhs: FixedDecimal<T, E2>,
+        strategy: RoundingStrategy,
+    ) -> Option<FixedDecimal<T, R>> {
+        let negative = self.0.is_positive() != rhs.0.is_positive();
+        let product = num_traits::CheckedMul::checked_mul(&self.0.widen(), &rhs.0.widen())?;
+        let product_scale = u32::from(E) + u32::from(E2);
+        let scaled = match u32::from(R).checked_sub(product_scale) {
+            Some(exp) => rounding::mul_pow10(product, exp)?,
+            None => {
+                let exp = product_scale - u32::from(R);
+                rounding::div_pow10_rounded(product, exp, negative, strategy)?
+            }
+        };
+        T::narrow(negative, scaled).map(FixedDecimal)
+    }
+
+    /// Divides two decimals rounding the last digit of the quotient with the
+    /// given strategy.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero or the result overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::{FixedDecimalI64, RoundingStrategy};
+    /// # use std::str::FromStr;
+    /// #
+    /// let total = FixedDecimalI64::<2>::from_str("10.00").unwrap();
+    /// let qty = FixedDecimalI64::<2>::from_str("3").unwrap();
+    /// let unit = total.div_with_rounding(qty, RoundingStrategy::ToPositiveInfinity);
+    /// assert_eq!(unit.to_string(), "3.34");
+    /// ```
+    #[must_use]
+    pub fn div_with_rounding(self, rhs: Self, strategy: RoundingStrategy) -> Self {
+        assert!(!rhs.0.widen().is_zero(), "attempt to divide by zero");
+        self.checked_div_with_rounding(rhs, strategy)
+            .expect("attempt to divide with overflow")
+    }
+
+    /// Checked decimal division, rounding with
+    /// [`RoundingStrategy::MidpointNearestEven`].
+    /// Returns `None` if `rhs` is zero or the result overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI8;
+    /// #
+    /// let a = FixedDecimalI8::<1>::new(10);
+    /// let b = FixedDecimalI8::<1>::new(30);
+    /// assert_eq!(a.checked_div(b).unwrap().to_string(), "0.3");
+    /// assert_eq!(b.checked_div(FixedDecimalI8::<1>::new(1)), None);
+    /// assert_eq!(a.checked_div(FixedDecimalI8::<1>::new(0)), None);
+    /// ```
+    #[must_use]
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.checked_div_with_rounding(rhs, RoundingStrategy::default())
+    }
+
+    /// Checked decimal division, rounding the last digit of the quotient with
+    /// the given strategy.
+    /// Returns `None` if `rhs` is zero or the result overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::{FixedDecimalI8, RoundingStrategy};
+    /// #
+    /// let a = FixedDecimalI8::<1>::new(-20);
+    /// let b = FixedDecimalI8::<1>::new(30);
+    /// let floor = a.checked_div_with_rounding(b, RoundingStrategy::ToNegativeInfinity);
+    /// assert_eq!(floor.unwrap().to_string(), "-0.7");
+    /// ```
+    #[must_use]
+    pub fn checked_div_with_rounding(self, rhs: Self, strategy: RoundingStrategy) -> Option<Self> {
+        let negative = self.0.is_positive() != rhs.0.is_positive();
+        let dividend = rounding::mul_pow10(self.0.widen(), E.into())?;
+        let quotient = rounding::div_rounded(dividend, rhs.0.widen(), negative, strategy)?;
+        T::narrow(negative, quotient).map(Self)
+    }
+
+    /// Computes `self * a / b` with a double width intermediate, rounding
+    /// only the final quotient with the given strategy.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `b` is zero or the result overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::{FixedDecimalI64, RoundingStrategy};
+    /// # use std::str::FromStr;
+    /// #
+    /// let amount = FixedDecimalI64::<2>::from_str("1234.56").unwrap();
+    /// let numerator = FixedDecimalI64::<2>::from_str("1.07").unwrap();
+    /// let denominator = FixedDecimalI64::<2>::from_str("0.97").unwrap();
+    ///
+    /// let fused = amount.mul_div(numerator, denominator, RoundingStrategy::default());
+    /// assert_eq!(fused.to_string(), "1361.83");
+    /// assert_eq!((amount * numerator / denominator).to_string(), "1361.84");
+    /// ```
+    #[must_use]
+    pub fn mul_div(self, a: Self, b: Self, strategy: RoundingStrategy) -> Self {
+        assert!(!b.0.widen().is_zero(), "attempt to divide by zero");
+        self.checked_mul_div(a, b, strategy)
+            .expect("attempt to multiply with overflow")
+    }
+
+    /// Checked `self * a / b` with a single rounding, see
+    /// [`FixedDecimal::mul_div`].
+    /// Returns `None` if `b` is zero or the result overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::{FixedDecimalI8, RoundingStrategy};
+    /// #
+    /// let a = FixedDecimalI8::<1>::new(120);
+    /// let b = FixedDecimalI8::<1>::new(30);
+    /// // The 36.0 intermediate product does not fit, the result does.
+    /// let result = a.checked_mul_div(b, b, RoundingStrategy::default());
+    /// assert_eq!(result, Some(a));
+    /// assert_eq!(a.checked_mul_div(b, FixedDecimalI8::<1>::new(0), RoundingStrategy::default()), None);
+    /// ```
+    #[must_use]
+    pub fn checked_mul_div(self, a: Self, b: Self, strategy: RoundingStrategy) -> Option<Self> {
+        // The scales cancel out: `x 10^-E * y 10^-E / (z 10^-E)` has the
+        // mantissa `x * y / z`.
+        let product_negative = self.0.is_positive() != a.0.is_positive();
+        let divisor_negative = !b.0.is_positive();
+        let negative = product_negative != divisor_negative;
+        let product = num_traits::CheckedMul::checked_mul(&self.0.widen(), &a.0.widen())?;
+        let quotient = rounding::div_rounded(product, b.0.widen(), negative, strategy)?;
+        T::narrow(negative, quotient).map(Self)
+    }
+
+    /// Computes `self * a + b` with a double width intermediate, rounding
+    /// only the final sum with [`RoundingStrategy::MidpointNearestEven`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI64;
+    /// # use std::str::FromStr;
+    /// #
+    /// let rate = FixedDecimalI64::<2>::from_str("0.05").unwrap();
+    /// let base = FixedDecimalI64::<2>::from_str("0.10").unwrap();
+    /// let fee = FixedDecimalI64::<2>::from_str("0.01").unwrap();
+    /// assert_eq!(rate.mul_add(base, fee).to_string(), "0.02");
+    /// assert_eq!((rate * base + fee).to_string(), "0.01");
+    /// ```
+    #[must_use]
+    pub fn mul_add(self, a: Self, b: Self) -> Self {
+        self.checked_mul_add(a, b)
+            .expect("attempt to multiply with overflow")
+    }
+
+    /// Checked `self * a + b` with a single rounding, see
+    /// [`FixedDecimal::mul_add`].
+    /// Returns `None` if the result overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI8;
+    /// #
+    /// let a = FixedDecimalI8::<1>::new(40);
+    /// // The 16.0 intermediate product does not fit, the result does.
+    /// let result = a.checked_mul_add(a, FixedDecimalI8::<1>::new(-120));
+    /// assert_eq!(result.unwrap().to_string(), "4.0");
+    /// assert_eq!(a.checked_mul_add(a, a), None);
+    /// ```
+    #[must_use]
+    pub fn checked_mul_add(self, a: Self, b: Self) -> Option<Self> {
+        let product_negative = self.0.is_positive() != a.0.is_positive();
+        let addend_negative = !b.0.is_positive();
+        let wide_sum = || {
+            let product = num_traits::CheckedMul::checked_mul(&self.0.widen(), &a.0.widen())?;
+            let addend = rounding::mul_pow10(b.0.widen(), E.into())?;
+            if product_negative == addend_negative {
+                num_traits::CheckedAdd::checked_add(&product, &addend)
+                    .map(|sum| (product_negative, sum))
+            } else if product >= addend {
+                num_traits::CheckedSub::checked_sub(&product, &addend)
+                    .map(|sum| (product_negative, sum))
+            } else {
+                num_traits::CheckedSub::checked_sub(&addend, &product)
+                    .map(|sum| (addend_negative, sum))
+            }
+        };
+        let (negative, rounded) = match wide_sum() {
+            Some((negative, sum)) => (
+                negative,
+                rounding::div_pow10_rounded(sum, E.into(), negative, RoundingStrategy::default())?,
+            ),
+            // The intermediates do not fit in the wide type, the result may.
+            None => {
+                let big = |n: T::Wide| n.try_into().ok().map(BigUint::from_u128);
+                let product = big(self.0.widen())?.mul(&big(a.0.widen())?);
+                let one = BigUint::pow10(E.into());
+                let addend = big(b.0.widen())?.mul(&one);
+                let (negative, sum) = if product_negative == addend_negative {
+                    (product_negative, product.add(&addend))
+                } else if product >= addend {
+                    (product_negative, product.sub(&addend))
+                } else {
+                    (addend_negative, addend.sub(&product))
+                };
+                let rounded =
+                    rounding::big_div_rounded(&sum, &one, negative, RoundingStrategy::default())?;
+                (negative, rounded)
+            }
+        };
+        T::narrow(negative, rounded).map(Self)
+    }
+}
+
+impl<T: num_traits::SaturatingAdd + num_traits::SaturatingSub, const E: u8> FixedDecimal<T, E> {
+    /// Saturating addition, clamping to [`FixedDecimal::MAX`] or
+    /// [`FixedDecimal::MIN`] instead of overflowing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI8;
+    /// #
+    /// let a = FixedDecimalI8::<1>::new(100);
+    /// assert_eq!(a.saturating_add(a), FixedDecimalI8::<1>::MAX);
+    /// assert_eq!((-a).saturating_add(-a), FixedDecimalI8::<1>::MIN);
+    /// ```
+    #[must_use]
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Self(self.0.saturating_add(&rhs.0))
+    }
+
+    /// Saturating subtraction, clamping to [`FixedDecimal::MAX`] or
+    /// [`FixedDecimal::MIN`] instead of overflowing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalU8;
+    /// #
+    /// let a = FixedDecimalU8::<1>::new(10);
+    /// assert_eq!(a.saturating_sub(a + a), FixedDecimalU8::<1>::MIN);
+    /// ```
+    #[must_use]
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self(self.0.saturating_sub(&rhs.0))
+    }
+}
+
+#[expect(private_bounds)]
+impl<T: ext_num_traits::Widen + ext_num_traits::ConstBound, const E: u8> FixedDecimal<T, E> {
+    fn saturate(self, rhs: Self) -> Self {
+        if self.0.is_positive() == rhs.0.is_positive() {
+            Self::MAX
+        } else {
+            Self::MIN
+        }
+    }
+
+    /// Saturating decimal multiplication, clamping to [`FixedDecimal::MAX`]
+    /// or [`FixedDecimal::MIN`] instead of overflowing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI8;
+    /// #
+    /// let a = FixedDecimalI8::<1>::new(15);
+    /// assert_eq!(a.saturating_mul(a).to_string(), "2.2");
+    /// assert_eq!(a.saturating_mul(FixedDecimalI8::<1>::MIN), FixedDecimalI8::<1>::MIN);
+    /// ```
+    #[must_use]
+    pub fn saturating_mul(self, rhs: Self) -> Self {
+        self.checked_mul(rhs).unwrap_or_else(|| self.saturate(rhs))
+    }
+
+    /// Saturating decimal division, clamping to [`FixedDecimal::MAX`] or
+    /// [`FixedDecimal::MIN`] instead of overflowing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI8;
+    /// #
+    /// let a = FixedDecimalI8::<1>::new(100);
+    /// let tenth = FixedDecimalI8::<1>::new(1);
+    /// assert_eq!(a.saturating_div(tenth), FixedDecimalI8::<1>::MAX);
+    /// assert_eq!(a.saturating_div(-tenth), FixedDecimalI8::<1>::MIN);
+    /// ```
+    #[must_use]
+    pub fn saturating_div(self, rhs: Self) -> Self {
+        assert!(!rhs.0.widen().is_zero(), "attempt to divide by zero");
+        self.checked_div(rhs).unwrap_or_else(|| self.saturate(rhs))
+    }
+}
+
+impl<T: num_traits::SaturatingAdd, const E: u8> num_traits::SaturatingAdd for FixedDecimal<T, E> {
+    fn saturating_add(&self, v: &Self) -> Self {
+        Self(self.0.saturating_add(&v.0))
+    }
+}
+
+impl<T: num_traits::SaturatingSub, const E: u8> num_traits::SaturatingSub for FixedDecimal<T, E> {
+    fn saturating_sub(&self, v: &Self) -> Self {
+        Self(self.0.saturating_sub(&v.0))
+    }
+}
+
+impl<T, const E: u8> num_traits::SaturatingMul for FixedDecimal<T, E>
+where
+    T: ext_num_traits::Widen + ext_num_traits::ConstBound,
+{
+    fn saturating_mul(&self, v: &Self) -> Self {
+        Self::saturating_mul(*self, *v)
+    }
+}
+
+impl<T, const E: u8> FixedDecimal<T, E>
+where
+    T: num_traits::WrappingAdd + num_traits::WrappingSub + num_traits::WrappingNeg,
+{
+    /// Wrapping addition, wraps around at the boundary of the backing type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI8;
+    /// #
+    /// let a = FixedDecimalI8::<1>::MAX;
+    /// assert_eq!(a.wrapping_add(FixedDecimalI8::new(1)), FixedDecimalI8::<1>::MIN);
+    /// ```
+    #[must_use]
+    pub fn wrapping_add(self, rhs: Self) -> Self {
+        Self(self.0.wrapping_add(&rhs.0))
+    }
+
+    /// Wrapping subtraction, wraps around at the boundary of the backing type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalU8;
+    /// #
+    /// let a = FixedDecimalU8::<1>::new(0);
+    /// assert_eq!(a.wrapping_sub(FixedDecimalU8::new(1)), FixedDecimalU8::<1>::MAX);
+    /// ```
+    #[must_use]
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        Self(self.0.wrapping_sub(&rhs.0))
+    }
+
+    /// Wrapping negation, `-MIN` wraps around to `MIN`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI8;
+    /// #
+    /// let a = FixedDecimalI8::<1>::MIN;
+    /// assert_eq!(a.wrapping_neg(), a);
+    /// ```
+    #[must_use]
+    pub fn wrapping_neg(self) -> Self {
+        Self(self.0.wrapping_neg())
+    }
+}
+
+impl<T: num_traits::WrappingMul, const E: u8> FixedDecimal<T, E> {
+    /// Wrapping multiplication by an integer, wraps around at the boundary
+    /// of the backing type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI8;
+    /// #
+    /// let a = FixedDecimalI8::<1>::new(64);
+    /// assert_eq!(a.wrapping_mul_int(2), FixedDecimalI8::<1>::MIN);
+    /// ```
+    #[must_use]
+    pub fn wrapping_mul_int(self, rhs: T) -> Self {
+        Self(self.0.wrapping_mul(&rhs))
+    }
+}
+
+impl<T, const E: u8> FixedDecimal<T, E>
+where
+    T: num_traits::ops::overflowing::OverflowingAdd
+        + num_traits::ops::overflowing::OverflowingSub
+        + num_traits::Zero,
+{
+    /// Addition returning the wrapped result along with a boolean telling
+    /// if it overflowed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI8;
+    /// #
+    /// let a = FixedDecimalI8::<1>::MAX;
+    /// let tenth = FixedDecimalI8::new(1);
+    /// assert_eq!(a.overflowing_add(tenth), (FixedDecimalI8::<1>::MIN, true));
+    /// assert_eq!(tenth.overflowing_add(tenth), (FixedDecimalI8::new(2), false));
+    /// ```
+    #[must_use]
+    pub fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        let (value, overflow) = self.0.overflowing_add(&rhs.0);
+        (Self(value), overflow)
+    }
+
+    /// Subtraction returning the wrapped result along with a boolean telling
+    /// if it overflowed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalU8;
+    /// #
+    /// let tenth = FixedDecimalU8::<1>::new(1);
+    /// assert_eq!(FixedDecimalU8::new(0).overflowing_sub(tenth), (FixedDecimalU8::<1>::MAX, true));
+    /// assert_eq!(tenth.overflowing_sub(tenth), (FixedDecimalU8::new(0), false));
+    /// ```
+    #[must_use]
+    pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        let (value, overflow) = self.0.overflowing_sub(&rhs.0);
+        (Self(value), overflow)
+    }
+
+    /// Negation returning the wrapped result along with a boolean telling
+    /// if it overflowed, which happens for `MIN` on signed backings and for
+    /// anything but zero on unsigned ones.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::{FixedDecimalI8, FixedDecimalU8};
+    /// #
+    /// let a = FixedDecimalI8::<1>::MIN;
+    /// assert_eq!(a.overflowing_neg(), (a, true));
+    /// assert_eq!(FixedDecimalU8::<1>::new(1).overflowing_neg(), (FixedDecimalU8::MAX, true));
+    /// ```
+    #[must_use]
+    pub fn overflowing_neg(self) -> (Self, bool) {
+        let (value, overflow) = T::zero().overflowing_sub(&self.0);
+        (Self(value), overflow)
+    }
+}
+
+impl<T: num_traits::ops::overflowing::OverflowingMul, const E: u8> FixedDecimal<T, E> {
+    /// Multiplication by an integer returning the wrapped result along with
+    /// a boolean telling if it overflowed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI8;
+    /// #
+    /// let a = FixedDecimalI8::<1>::new(64);
+    /// assert_eq!(a.overflowing_mul_int(2), (FixedDecimalI8::<1>::MIN, true));
+    /// assert_eq!(a.overflowing_mul_int(1), (a, false));
+    /// ```
+    #[must_use]
+    pub fn overflowing_mul_int(self, rhs: T) -> (Self, bool) {
+        let (value, overflow) = self.0.overflowing_mul(&rhs);
+        (Self(value), overflow)
+    }
+}
+
+#[expect(private_bounds)]
+impl<T: ext_num_traits::Widen, const E: u8> FixedDecimal<T, E> {
+    /// Decimal multiplication, rounding with
+    /// [`RoundingStrategy::MidpointNearestEven`], returning the result
+    /// wrapped around at the boundary of the backing type along with a
+    /// boolean telling if it overflowed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI8;
+    /// #
+    /// let a = FixedDecimalI8::<1>::new(20);
+    /// assert_eq!(a.overflowing_mul(a), (FixedDecimalI8::new(40), false));
+    /// let b = FixedDecimalI8::<1>::new(80);
+    /// assert_eq!(a.overflowing_mul(b), (FixedDecimalI8::new(-96), true));
+    /// ```
+    #[must_use]
+    pub fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+        let negative = self.0.is_positive() != rhs.0.is_positive();
+        let (a, b) = (self.0.widen(), rhs.0.widen());
+        let rounded = num_traits::CheckedMul::checked_mul(&a, &b).and_then(|product| {
+            rounding::div_pow10_rounded(product, E.into(), negative, RoundingStrategy::default())
+        });
+        match rounded {
+            Some(rounded) => match T::narrow(negative, rounded) {
+                Some(value) => (Self(value), false),
+                None => (Self(T::narrow_wrapping(negative, rounded)), true),
+            },
+            None => {
+                let product = num_traits::WrappingMul::wrapping_mul(&a, &b);
+                (Self(T::narrow_wrapping(negative, product)), true)
+            }
+        }
+    }
+
+    /// Decimal multiplication, rounding with
+    /// [`RoundingStrategy::MidpointNearestEven`], wrapping around at the
+    /// boundary of the backing type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalU8;
+    /// #
+    /// let a = FixedDecimalU8::<1>::new(20);
+    /// assert_eq!(a.wrapping_mul(FixedDecimalU8::new(130)), FixedDecimalU8::<1>::new(4));
+    /// ```
+    #[must_use]
+    pub fn wrapping_mul(self, rhs: Self) -> Self {
+        self.overflowing_mul(rhs).0
+    }
+}
+
+impl<T: num_traits::WrappingAdd, const E: u8> num_traits::WrappingAdd for FixedDecimal<T, E> {
+    fn wrapping_add(&self, v: &Self) -> Self {
+        Self(self.0.wrapping_add(&v.0))
+    }
+}
+
+impl<T: num_traits::WrappingSub, const E: u8> num_traits::WrappingSub for FixedDecimal<T, E> {
+    fn wrapping_sub(&self, v: &Self) -> Self {
+        Self(self.0.wrapping_sub(&v.0))
+    }
+}
+
+impl<T: ext_num_traits::Widen, const E: u8> num_traits::WrappingMul for FixedDecimal<T, E> {
+    fn wrapping_mul(&self, v: &Self) -> Self {
+        Self::wrapping_mul(*self, *v)
+    }
+}
+
+impl<T: num_traits::WrappingNeg, const E: u8> num_traits::WrappingNeg for FixedDecimal<T, E> {
+    fn wrapping_neg(&self) -> Self {
+        Self(self.0.wrapping_neg())
+    }
+}
+
+#[expect(private_bounds)]
+impl<T: ext_num_traits::Widen, const E: u8> FixedDecimal<T, E> {
+    /// Rounds to `places` decimal places with the given strategy, keeping the
+    /// type's scale.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::{FixedDecimalI64, RoundingStrategy};
+    /// # use std::str::FromStr;
+    /// #
+    /// let a = FixedDecimalI64::<4>::from_str("-1.2350").unwrap();
+    /// assert_eq!(a.round_dp(2, RoundingStrategy::MidpointNearestEven).to_string(), "-1.2400");
+    /// assert_eq!(a.round_dp(2, RoundingStrategy::MidpointTowardZero).to_string(), "-1.2300");
+    /// assert_eq!(a.round_dp(5, RoundingStrategy::ToZero), a);
+    /// ```
+    #[must_use]
+    pub fn round_dp(self, places: u8, strategy: RoundingStrategy) -> Self {
+        self.checked_round_dp(places, strategy)
+            .expect("attempt to round with overflow")
+    }
+
+    /// Rounds to `places` decimal places with the given strategy, keeping the
+    /// type's scale.
+    /// Returns `None` if the result overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::{FixedDecimalI8, RoundingStrategy};
+    /// #
+    /// let a = FixedDecimalI8::<1>::new(125);
+    /// assert_eq!(a.checked_round_dp(0, RoundingStrategy::ToZero), Some(FixedDecimalI8::new(120)));
+    /// assert_eq!(a.checked_round_dp(0, RoundingStrategy::AwayFromZero), None);
+    /// ```
+    #[must_use]
+    pub fn checked_round_dp(self, places: u8, strategy: RoundingStrategy) -> Option<Self> {
+        let Some(exp) = E.checked_sub(places).filter(|exp| *exp > 0) else {
+            return Some(self);
+        };
+        let negative = !self.0.is_positive();
+        let rounded = rounding::div_pow10_rounded(self.0.widen(), exp.into(), negative, strategy)?;
+        T::narrow(negative, rounding::mul_pow10(rounded, exp.into())?).map(Self)
+    }
+
+    /// Integer part of the number, dropping the fractional digits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI64;
+    /// # use std::str::FromStr;
+    /// #
+    /// let a = FixedDecimalI64::<2>::from_str("-3.99").unwrap();
+    /// assert_eq!(a.trunc().to_string(), "-3.00");
+    /// ```
+    #[must_use]
+    pub fn trunc(self) -> Self {
+        self.round_dp(0, RoundingStrategy::ToZero)
+    }
+
+    /// Fractional part of the number, with the same sign as `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI64;
+    /// # use std::str::FromStr;
+    /// #
+    /// let a = FixedDecimalI64::<2>::from_str("-3.99").unwrap();
+    /// assert_eq!(a.fract().to_string(), "-0.99");
+    /// ```
+    #[must_use]
+    pub fn fract(self) -> Self {
+        let negative = !self.0.is_positive();
+        let fract = rounding::rem_pow10(self.0.widen(), E.into());
+        Self(T::narrow(negative, fract).expect("fractional part is smaller than self"))
+    }
+
+    /// Largest integer less than or equal to `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI64;
+    /// # use std::str::FromStr;
+    /// #
+    /// let a = FixedDecimalI64::<2>::from_str("-3.01").unwrap();
+    /// assert_eq!(a.floor().to_string(), "-4.00");
+    /// assert_eq!((-a).floor().to_string(), "3.00");
+    /// ```
+    #[must_use]
+    pub fn floor(self) -> Self {
+        self.round_dp(0, RoundingStrategy::ToNegativeInfinity)
+    }
+
+    /// Smallest integer greater than or equal to `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI64;
+    /// # use std::str::FromStr;
+    /// #
+    /// let a = FixedDecimalI64::<2>::from_str("-3.99").unwrap();
+    /// assert_eq!(a.ceil().to_string(), "-3.00");
+    /// assert_eq!((-a).ceil().to_string(), "4.00");
+    /// ```
+    #[must_use]
+    pub fn ceil(self) -> Self {
+        self.round_dp(0, RoundingStrategy::ToPositiveInfinity)
+    }
+
+    /// Nearest integer, ties rounded to the even one
+    /// ([`RoundingStrategy::MidpointNearestEven`]).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI64;
+    /// # use std::str::FromStr;
+    /// #
+    /// let a = FixedDecimalI64::<1>::from_str("2.5").unwrap();
+    /// assert_eq!(a.round().to_string(), "2.0");
+    /// let a = FixedDecimalI64::<1>::from_str("-3.5").unwrap();
+    /// assert_eq!(a.round().to_string(), "-4.0");
+    /// ```
+    #[must_use]
+    pub fn round(self) -> Self {
+        self.round_dp(0, RoundingStrategy::MidpointNearestEven)
+    }
+}
+
+#[expect(private_bounds)]
+impl<T: ext_num_traits::Widen, const E: u8> FixedDecimal<T, E> {
+    /// Converts to another scale keeping the value, rounding with the given
+    /// strategy when the target scale is smaller.
+    /// Unlike [`FixedDecimal::with_scale`] the mantissa is multiplied or
+    /// divided by the matching power of ten.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::{FixedDecimalI64, RoundingStrategy};
+    /// # use std::str::FromStr;
+    /// #
+    /// let a = FixedDecimalI64::<2>::from_str("1.23").unwrap();
+    /// assert_eq!(a.rescale::<4>(RoundingStrategy::default()).to_string(), "1.2300");
+    /// assert_eq!(a.rescale::<1>(RoundingStrategy::ToPositiveInfinity).to_string(), "1.3");
+    /// ```
+    #[must_use]
+    pub fn rescale<const TARGET_SCALE: u8>(
+        self,
+        strategy: RoundingStrategy,
+    ) -> FixedDecimal<T, TARGET_SCALE> {
+        self.checked_rescale(strategy)
+            .expect("attempt to rescale with overflow")
+    }
+
+    /// Converts to another scale keeping the value, rounding with the given
+    /// strategy when the target scale is smaller.
+    /// Returns `None` if the result overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::{FixedDecimalI8, RoundingStrategy};
+    /// #
+    /// let a = FixedDecimalI8::<1>::new(12);
+    /// assert_eq!(a.checked_rescale::<2>(RoundingStrategy::default()), Some(FixedDecimalI8::new(120)));
+    /// assert_eq!(a.checked_rescale::<3>(RoundingStrategy::default()), None);
+    /// ```
+    #[must_use]
+    pub fn checked_rescale<const TARGET_SCALE: u8>(
+        self,
+        strategy: RoundingStrategy,
+    ) -> Option<FixedDecimal<T, TARGET_SCALE>> {
+        let negative = !self.0.is_positive();
+        let scaled = match TARGET_SCALE.checked_sub(E) {
+            Some(exp) => rounding::mul_pow10(self.0.widen(), exp.into())?,
+            None => {
+                let exp = E - TARGET_SCALE;
+                rounding::div_pow10_rounded(self.0.widen(), exp.into(), negative, strategy)?
+            }
+        };
+        T::narrow(negative, scaled).map(FixedDecimal)
+    }
+
+    /// Converts to another scale keeping the value, failing if any digit
+    /// would be lost or the result overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::{ArithmeticError, FixedDecimalI64};
+    /// # use std::str::FromStr;
+    /// #
+    /// let a = FixedDecimalI64::<4>::from_str("1.2300").unwrap();
+    /// assert_eq!(a.exact_rescale::<2>().unwrap().to_string(), "1.23");
+    /// assert_eq!(a.exact_rescale::<1>(), Err(ArithmeticError::Underflow));
+    /// ```
+    pub fn exact_rescale<const TARGET_SCALE: u8>(
+        self,
+    ) -> Result<FixedDecimal<T, TARGET_SCALE>, ArithmeticError> {
+        let negative = !self.0.is_positive();
+        if let Some(exp) = E.checked_sub(TARGET_SCALE) {
+            if !rounding::rem_pow10(self.0.widen(), exp.into()).is_zero() {
+                return Err(ArithmeticError::Underflow);
+            }
+        }
+        self.checked_rescale(RoundingStrategy::ToZero)
+            .ok_or(ArithmeticError::overflow(negative))
+    }
+}
+
 #[expect(private_bounds)]
 impl<T: ext_num_traits::ConstTenPow<E>, const E: u8> FixedDecimal<T, E> {
     pub const ONE: Self = Self(T::RESULT);
@@ -0,0 +1,404 @@
+use std::ops;
+
+use num_traits::Zero;
+
+use crate::{ext_num_traits, rounding, FixedDecimal, RoundingStrategy};
+
+/// `n * 10^E` with the backing integer `*`, so overflow behaves as for the
+/// integer.
+fn scale_int<T: ops::Mul<Output = T> + ext_num_traits::ConstTen + Copy, const E: u8>(n: T) -> T {
+    (0..E).fold(n, |scaled, _| scaled * T::TEN)
+}
+
+/// Adds an integer taken as a whole number at the scale `E`, scaling and
+/// adding it with the backing integer operators, so overflow behaves as for
+/// the integer: it panics when debug assertions are enabled and wraps
+/// otherwise. See [`FixedDecimal::checked_add_int`] for an explicit behavior.
+impl<T, const E: u8> ops::Add<T> for FixedDecimal<T, E>
+where
+    T: ops::Add<Output = T> + ops::Mul<Output = T> + ext_num_traits::ConstTen + Copy,
+{
+    type Output = Self;
+
+    fn add(self, rhs: T) -> Self::Output {
+        Self(self.0 + scale_int::<T, E>(rhs))
+    }
+}
+
+/// Subtracts an integer taken as a whole number at the scale `E`, scaling
+/// and subtracting it with the backing integer operators, so overflow
+/// behaves as for the integer: it panics when debug assertions are enabled
+/// and wraps otherwise. See [`FixedDecimal::checked_sub_int`] for an
+/// explicit behavior.
+impl<T, const E: u8> ops::Sub<T> for FixedDecimal<T, E>
+where
+    T: ops::Sub<Output = T> + ops::Mul<Output = T> + ext_num_traits::ConstTen + Copy,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: T) -> Self::Output {
+        Self(self.0 - scale_int::<T, E>(rhs))
+    }
+}
+
+/// Divides by an integer, rounding the result with
+/// [`RoundingStrategy::MidpointNearestEven`].
+///
+/// # Panics
+///
+/// Panics if `rhs` is zero or the result overflows, see
+/// [`FixedDecimal::checked_div_int`].
+impl<T, const E: u8> ops::Div<T> for FixedDecimal<T, E>
+where
+    T: ext_num_traits::Widen + num_traits::CheckedAdd + num_traits::CheckedSub,
+{
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        assert!(!rhs.widen().is_zero(), "attempt to divide by zero");
+        self.checked_div_int(rhs)
+            .expect("attempt to divide with overflow")
+    }
+}
+
+/// Remainder of the truncated division by an integer taken as a whole number
+/// at the scale `E`, with the same sign as `self`.
+///
+/// # Panics
+///
+/// Panics if `rhs` is zero.
+impl<T, const E: u8> ops::Rem<T> for FixedDecimal<T, E>
+where
+    T: ext_num_traits::Widen + num_traits::CheckedAdd + num_traits::CheckedSub,
+{
+    type Output = Self;
+
+    fn rem(self, rhs: T) -> Self::Output {
+        self.checked_rem_int(rhs)
+            .expect("attempt to calculate the remainder with a divisor of zero")
+    }
+}
+
+/// Borrowed forms of the integer operators, forwarding to the owned ones.
+macro_rules! forward_ref_int_binop {
+    ($($imp:ident, $method:ident);+) => {$(
+        impl<T: Copy, const E: u8> ops::$imp<T> for &FixedDecimal<T, E>
+        where
+            FixedDecimal<T, E>: ops::$imp<T, Output = FixedDecimal<T, E>>,
+        {
+            type Output = FixedDecimal<T, E>;
+
+            fn $method(self, rhs: T) -> Self::Output {
+                ops::$imp::$method(*self, rhs)
+            }
+        }
+
+        impl<T: Copy, const E: u8> ops::$imp<&T> for FixedDecimal<T, E>
+        where
+            FixedDecimal<T, E>: ops::$imp<T, Output = FixedDecimal<T, E>>,
+        {
+            type Output = FixedDecimal<T, E>;
+
+            fn $method(self, rhs: &T) -> Self::Output {
+                ops::$imp::$method(self, *rhs)
+            }
+        }
+
+        impl<T: Copy, const E: u8> ops::$imp<&T> for &FixedDecimal<T, E>
+        where
+            FixedDecimal<T, E>: ops::$imp<T, Output = FixedDecimal<T, E>>,
+        {
+            type Output = FixedDecimal<T, E>;
+
+            fn $method(self, rhs: &T) -> Self::Output {
+                ops::$imp::$method(*self, *rhs)
+            }
+        }
+    )+};
+}
+
+forward_ref_int_binop!(Add, add; Sub, sub; Mul, mul; Div, div; Rem, rem);
+
+/// Assign forms of the integer operators with an owned or borrowed right
+/// hand side, with the same rounding and overflow behavior as the operator.
+macro_rules! int_assign_binop {
+    ($($imp:ident, $method:ident, $op:ident, $op_method:ident);+) => {$(
+        impl<T: Copy, const E: u8> ops::$imp<T> for FixedDecimal<T, E>
+        where
+            FixedDecimal<T, E>: ops::$op<T, Output = FixedDecimal<T, E>>,
+        {
+            fn $method(&mut self, rhs: T) {
+                *self = ops::$op::$op_method(*self, rhs);
+            }
+        }
+
+        impl<T: Copy, const E: u8> ops::$imp<&T> for FixedDecimal<T, E>
+        where
+            FixedDecimal<T, E>: ops::$op<T, Output = FixedDecimal<T, E>>,
+        {
+            fn $method(&mut self, rhs: &T) {
+                *self = ops::$op::$op_method(*self, *rhs);
+            }
+        }
+    )+};
+}
+
+int_assign_binop!(
+    AddAssign, add_assign, Add, add;
+    SubAssign, sub_assign, Sub, sub;
+    MulAssign, mul_assign, Mul, mul;
+    DivAssign, div_assign, Div, div;
+    RemAssign, rem_assign, Rem, rem
+);
+
+#[expect(private_bounds)]
+impl<T, const E: u8> FixedDecimal<T, E>
+where
+    T: ext_num_traits::Widen + num_traits::CheckedAdd + num_traits::CheckedSub,
+{
+    /// Checked addition of an integer taken as a whole number at the scale
+    /// `E`.
+    /// Returns `None` if `rhs * 10^E` or the result overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI8;
+    /// #
+    /// let a = FixedDecimalI8::<1>::new(-15);
+    /// assert_eq!(a.checked_add_int(2).unwrap().to_string(), "0.5");
+    /// assert_eq!(a.checked_add_int(13), None);
+    /// ```
+    #[must_use]
+    pub fn checked_add_int(self, rhs: T) -> Option<Self> {
+        let rhs = Self::checked_from_int(rhs)?;
+        self.0.checked_add(&rhs.0).map(Self)
+    }
+
+    /// Checked subtraction of an integer taken as a whole number at the
+    /// scale `E`.
+    /// Returns `None` if `rhs * 10^E` or the result overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalU8;
+    /// #
+    /// let a = FixedDecimalU8::<1>::new(15);
+    /// assert_eq!(a.checked_sub_int(1).unwrap().to_string(), "0.5");
+    /// assert_eq!(a.checked_sub_int(2), None);
+    /// ```
+    #[must_use]
+    pub fn checked_sub_int(self, rhs: T) -> Option<Self> {
+        let rhs = Self::checked_from_int(rhs)?;
+        self.0.checked_sub(&rhs.0).map(Self)
+    }
+
+    /// Checked division by an integer, rounding with
+    /// [`RoundingStrategy::MidpointNearestEven`].
+    /// Returns `None` if `rhs` is zero or the result overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI64;
+    /// # use std::str::FromStr;
+    /// #
+    /// let total = FixedDecimalI64::<2>::from_str("10.00").unwrap();
+    /// assert_eq!(total.checked_div_int(3).unwrap().to_string(), "3.33");
+    /// assert_eq!(total.checked_div_int(0), None);
+    /// ```
+    #[must_use]
+    pub fn checked_div_int(self, rhs: T) -> Option<Self> {
+        // The integer has no fractional digits, the mantissa is divided
+        // as is.
+        let negative = self.0.is_positive() != rhs.is_positive();
+        let quotient = rounding::div_rounded(
+            self.0.widen(),
+            rhs.widen(),
+            negative,
+            RoundingStrategy::default(),
+        )?;
+        T::narrow(negative, quotient).map(Self)
+    }
+
+    /// Checked remainder of the truncated division by an integer taken as a
+    /// whole number at the scale `E`, with the same sign as `self`.
+    /// Returns `None` if `rhs` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI64;
+    /// # use std::str::FromStr;
+    /// #
+    /// let a = FixedDecimalI64::<2>::from_str("-10.50").unwrap();
+    /// assert_eq!(a.checked_rem_int(3).unwrap().to_string(), "-1.50");
+    /// assert_eq!(a.checked_rem_int(0), None);
+    /// ```
+    #[must_use]
+    pub fn checked_rem_int(self, rhs: T) -> Option<Self> {
+        let divisor = rhs.widen();
+        if divisor.is_zero() {
+            return None;
+        }
+        // A divisor that does not fit in `T` once scaled is bigger than any
+        // `self`, which is then the remainder.
+        let remainder = match rounding::mul_pow10(divisor, E.into()) {
+            Some(divisor) => self.0.widen() % divisor,
+            None => self.0.widen(),
+        };
+        T::narrow(!self.0.is_positive(), remainder).map(Self)
+    }
+
+    /// Checked `lhs - rhs` with `lhs` taken as a whole number at the scale
+    /// `E`, the reversed form of [`FixedDecimal::checked_sub_int`].
+    /// Returns `None` if `lhs * 10^E` or the result overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalU8;
+    /// #
+    /// let a = FixedDecimalU8::<1>::new(15);
+    /// assert_eq!(FixedDecimalU8::checked_int_sub(2, a).unwrap().to_string(), "0.5");
+    /// assert_eq!(FixedDecimalU8::checked_int_sub(1, a), None);
+    /// ```
+    #[must_use]
+    pub fn checked_int_sub(lhs: T, rhs: Self) -> Option<Self> {
+        let lhs = Self::checked_from_int(lhs)?;
+        lhs.0.checked_sub(&rhs.0).map(Self)
+    }
+
+    /// Checked `lhs / rhs` with `lhs` taken as a whole number at the scale
+    /// `E`, rounding with [`RoundingStrategy::MidpointNearestEven`].
+    /// Returns `None` if `rhs` is zero or if the result overflows, `lhs`
+    /// itself does not have to fit at the scale `E`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI64;
+    /// # use std::str::FromStr;
+    /// #
+    /// let rate = FixedDecimalI64::<4>::from_str("0.0300").unwrap();
+    /// assert_eq!(FixedDecimalI64::checked_int_div(1, rate).unwrap().to_string(), "33.3333");
+    /// assert_eq!(FixedDecimalI64::checked_int_div(1, FixedDecimalI64::<4>::new(0)), None);
+    /// // 100 does not fit at scale 17, 100 / 20 does.
+    /// let twenty = FixedDecimalI64::<17>::from_int(20);
+    /// assert_eq!(FixedDecimalI64::checked_int_div(100, twenty).unwrap().to_string(), "5.00000000000000000");
+    /// ```
+    #[must_use]
+    pub fn checked_int_div(lhs: T, rhs: Self) -> Option<Self> {
+        // `lhs * 10^(2 * E) / rhs` gives the quotient at the scale `E`. The
+        // wide type has twice the bits of `T`, so the quotient overflows
+        // whenever the scaled dividend does.
+        let negative = lhs.is_positive() != rhs.0.is_positive();
+        let dividend = rounding::mul_pow10(lhs.widen(), 2 * u32::from(E))?;
+        let quotient = rounding::div_rounded(
+            dividend,
+            rhs.0.widen(),
+            negative,
+            RoundingStrategy::default(),
+        )?;
+        T::narrow(negative, quotient).map(Self)
+    }
+
+    /// Checked `lhs % rhs` with `lhs` taken as a whole number at the scale
+    /// `E`, with the same sign as `lhs`.
+    /// Returns `None` if `rhs` is zero or if `lhs * 10^E` overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI64;
+    /// # use std::str::FromStr;
+    /// #
+    /// let lot = FixedDecimalI64::<2>::from_str("0.75").unwrap();
+    /// assert_eq!(FixedDecimalI64::checked_int_rem(-10, lot).unwrap().to_string(), "-0.25");
+    /// ```
+    #[must_use]
+    pub fn checked_int_rem(lhs: T, rhs: Self) -> Option<Self> {
+        let lhs = Self::checked_from_int(lhs)?;
+        let divisor = rhs.0.widen();
+        if divisor.is_zero() {
+            return None;
+        }
+        T::narrow(!lhs.0.is_positive(), lhs.0.widen() % divisor).map(Self)
+    }
+}
+
+/// Reversed forms of the integer operators, `int op decimal`, with the same
+/// semantics as `decimal op int`.
+macro_rules! int_lhs_impl {
+    ($($tty:ty),+) => {$(
+        /// Adds a decimal to an integer taken as a whole number at the scale
+        /// `E`, with the overflow behavior of `decimal + int`.
+        impl<const E: u8> ops::Add<FixedDecimal<$tty, E>> for $tty {
+            type Output = FixedDecimal<$tty, E>;
+
+            fn add(self, rhs: FixedDecimal<$tty, E>) -> Self::Output {
+                rhs + self
+            }
+        }
+
+        /// Subtracts a decimal from an integer taken as a whole number at the
+        /// scale `E`, with the overflow behavior of `decimal - int`.
+        impl<const E: u8> ops::Sub<FixedDecimal<$tty, E>> for $tty {
+            type Output = FixedDecimal<$tty, E>;
+
+            fn sub(self, rhs: FixedDecimal<$tty, E>) -> Self::Output {
+                FixedDecimal(scale_int::<$tty, E>(self)) - rhs
+            }
+        }
+
+        /// Multiplies a decimal by an integer, with the overflow behavior of
+        /// `decimal * int`.
+        impl<const E: u8> ops::Mul<FixedDecimal<$tty, E>> for $tty {
+            type Output = FixedDecimal<$tty, E>;
+
+            fn mul(self, rhs: FixedDecimal<$tty, E>) -> Self::Output {
+                rhs * self
+            }
+        }
+
+        /// Divides an integer by a decimal, rounding the result with
+        /// [`RoundingStrategy::MidpointNearestEven`].
+        ///
+        /// # Panics
+        ///
+        /// Panics if `rhs` is zero or the result overflows, see
+        /// [`FixedDecimal::checked_int_div`].
+        impl<const E: u8> ops::Div<FixedDecimal<$tty, E>> for $tty {
+            type Output = FixedDecimal<$tty, E>;
+
+            fn div(self, rhs: FixedDecimal<$tty, E>) -> Self::Output {
+                assert!(rhs.0 != 0, "attempt to divide by zero");
+                FixedDecimal::checked_int_div(self, rhs)
+                    .expect("attempt to divide with overflow")
+            }
+        }
+
+        /// Remainder of the truncated division of an integer taken as a whole
+        /// number at the scale `E` by a decimal, with the same sign as `self`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `rhs` is zero or if `self * 10^E` overflows, see
+        /// [`FixedDecimal::checked_int_rem`].
+        impl<const E: u8> ops::Rem<FixedDecimal<$tty, E>> for $tty {
+            type Output = FixedDecimal<$tty, E>;
+
+            fn rem(self, rhs: FixedDecimal<$tty, E>) -> Self::Output {
+                assert!(
+                    rhs.0 != 0,
+                    "attempt to calculate the remainder with a divisor of zero"
+                );
+                FixedDecimal::checked_int_rem(self, rhs)
+                    .expect("attempt to scale integer with overflow")
+            }
+        }
+    )+};
+}
+
+int_lhs_impl!(i128, i64, i32, i16, i8, u128, u64, u32, u16, u8);
@@ -0,0 +1,105 @@
+use std::{borrow::Borrow, iter, ops};
+
+use num_traits::{CheckedAdd, Zero};
+
+use crate::{ext_num_traits, ArithmeticError, FixedDecimal};
+
+/// Adds the items with [`ops::Add`], so overflow behaves as for the backing
+/// integer. See [`FixedDecimal::checked_sum`] and [`FixedDecimal::try_sum`]
+/// for explicit behaviors.
+impl<T: ops::Add<Output = T> + Zero, const E: u8> iter::Sum for FixedDecimal<T, E> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), ops::Add::add)
+    }
+}
+
+impl<'a, T: ops::Add<Output = T> + Zero + Copy, const E: u8> iter::Sum<&'a FixedDecimal<T, E>>
+    for FixedDecimal<T, E>
+{
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+/// Multiplies the items with the decimal [`ops::Mul`], rounding every step
+/// with [`RoundingStrategy::MidpointNearestEven`](crate::RoundingStrategy).
+///
+/// # Panics
+///
+/// Panics if any intermediate product overflows.
+impl<T, const E: u8> iter::Product for FixedDecimal<T, E>
+where
+    T: ext_num_traits::Widen + ext_num_traits::ConstTenPow<E>,
+{
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, ops::Mul::mul)
+    }
+}
+
+impl<'a, T, const E: u8> iter::Product<&'a FixedDecimal<T, E>> for FixedDecimal<T, E>
+where
+    T: ext_num_traits::Widen + ext_num_traits::ConstTenPow<E>,
+{
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+#[expect(private_bounds)]
+impl<T, const E: u8> FixedDecimal<T, E>
+where
+    T: CheckedAdd + Zero + ext_num_traits::Sign + Copy,
+{
+    /// Sums owned or borrowed items, returning `None` as soon as a partial
+    /// sum overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI8;
+    /// #
+    /// let entries = [FixedDecimalI8::<1>::new(15), FixedDecimalI8::<1>::new(-5)];
+    /// assert_eq!(FixedDecimalI8::checked_sum(&entries).unwrap().to_string(), "1.0");
+    ///
+    /// let entries = [FixedDecimalI8::<1>::MAX, FixedDecimalI8::<1>::new(1)];
+    /// assert_eq!(FixedDecimalI8::checked_sum(entries), None);
+    /// ```
+    #[must_use]
+    pub fn checked_sum<I>(iter: I) -> Option<Self>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Self>,
+    {
+        Self::try_sum(iter).ok()
+    }
+
+    /// Sums owned or borrowed items, failing as soon as a partial sum
+    /// overflows.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArithmeticError::PosOverflow`] or [`ArithmeticError::NegOverflow`]
+    /// depending on the direction of the overflowing partial sum.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::{ArithmeticError, FixedDecimalI8};
+    /// #
+    /// let entries = vec![FixedDecimalI8::<1>::MIN, FixedDecimalI8::<1>::new(-1)];
+    /// assert_eq!(FixedDecimalI8::try_sum(&entries), Err(ArithmeticError::NegOverflow));
+    /// ```
+    pub fn try_sum<I>(iter: I) -> Result<Self, ArithmeticError>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Self>,
+    {
+        iter.into_iter().try_fold(Self::zero(), |acc, item| {
+            let item = *item.borrow();
+            acc.0
+                .checked_add(&item.0)
+                .map(Self)
+                .ok_or(ArithmeticError::overflow(!item.0.is_positive()))
+        })
+    }
+}
@@ -1,13 +1,25 @@
+mod allocation;
+mod bignum;
 mod constants;
+mod convert;
 mod error;
 mod ext_num_traits;
+mod extended;
 mod fixed_decimal;
+mod integer_ops;
+mod iter;
+mod math;
+mod residual;
+mod rounding;
 mod str;
+mod wide;
 
 #[cfg(feature = "serde")]
 mod serde;
 
+pub use error::ArithmeticError;
 pub use fixed_decimal::FixedDecimal;
+pub use rounding::RoundingStrategy;
 
 pub type FixedDecimalI128<const E: u8> = FixedDecimal<i128, E>;
 pub type FixedDecimalU128<const E: u8> = FixedDecimal<u128, E>;
@@ -0,0 +1,447 @@
+use num_traits::Zero;
+
+use crate::{
+    bignum::BigUint, ext_num_traits, extended::Extended, rounding, FixedDecimal, RoundingStrategy,
+};
+
+/// Integer powers whose operands fit in this many bits are computed exactly,
+/// bigger ones with extended precision.
+const EXACT_POWER_BITS: u64 = 1 << 16;
+
+#[expect(private_bounds)]
+impl<T: ext_num_traits::Widen, const E: u8> FixedDecimal<T, E> {
+    /// Raises to an integer power.
+    ///
+    /// The power is computed exactly, or with extended precision for very
+    /// big exponents, and rounded once with
+    /// [`RoundingStrategy::MidpointNearestEven`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result overflows or if `self` is zero and `n` is
+    /// negative, see [`FixedDecimal::checked_powi`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI64;
+    /// # use std::str::FromStr;
+    /// #
+    /// let factor = FixedDecimalI64::<8>::from_str("1.0005").unwrap();
+    /// assert_eq!(factor.powi(30).to_string(), "1.01510926");
+    /// assert_eq!(factor.powi(-1).to_string(), "0.99950025");
+    /// ```
+    #[must_use]
+    pub fn powi(self, n: i32) -> Self {
+        assert!(
+            n >= 0 || !self.0.widen().is_zero(),
+            "attempt to divide by zero"
+        );
+        self.checked_powi(n)
+            .expect("attempt to multiply with overflow")
+    }
+
+    /// Checked integer power.
+    /// Returns `None` if the result overflows or if `self` is zero and `n`
+    /// is negative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI32;
+    /// #
+    /// let a = FixedDecimalI32::<2>::new(150);
+    /// assert_eq!(a.checked_powi(3).unwrap().to_string(), "3.38");
+    /// assert_eq!(a.checked_powi(100), None);
+    /// assert_eq!(FixedDecimalI32::<2>::new(0).checked_powi(-1), None);
+    /// ```
+    #[must_use]
+    pub fn checked_powi(self, n: i32) -> Option<Self> {
+        self.checked_pow_int(n.unsigned_abs().into(), n < 0)
+    }
+
+    /// `self^n`, or `1 / self^n` if `reciprocal`, rounded once with
+    /// [`RoundingStrategy::MidpointNearestEven`].
+    fn checked_pow_int(self, n: u64, reciprocal: bool) -> Option<Self> {
+        let magnitude = BigUint::from_u128(self.0.widen().try_into().ok()?);
+        let one = BigUint::pow10(E.into());
+        if n.saturating_add(1)
+            .saturating_mul(magnitude.bits().max(one.bits()))
+            > EXACT_POWER_BITS
+        {
+            let mut base = Extended::from_fixed(self);
+            if reciprocal {
+                base = Extended::from_u128(1).div(&base)?;
+            }
+            return base.powi(n)?.to_fixed(RoundingStrategy::default());
+        }
+        // The mantissa of `(m / 10^E)^n` is `m^n * 10^E / 10^(E * n)`, the
+        // one of its reciprocal `10^(E * (n + 1)) / m^n`.
+        let negative = !self.0.is_positive() && n & 1 == 1;
+        let power = magnitude.pow(n);
+        let scale = BigUint::pow10(u32::try_from(n * u64::from(E)).ok()?);
+        let (dividend, divisor) = if reciprocal {
+            (scale.mul(&one), power)
+        } else {
+            (power.mul(&one), scale)
+        };
+        rounding::big_div_rounded(&dividend, &divisor, negative, RoundingStrategy::default())
+            .and_then(|power| T::narrow(negative, power))
+            .map(Self)
+    }
+
+    /// Raises to a decimal power.
+    ///
+    /// Integer exponents are computed as [`FixedDecimal::powi`], any other
+    /// one as `exp(exponent * ln(self))` with extended precision, both
+    /// rounded once with [`RoundingStrategy::MidpointNearestEven`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result overflows, if `self` is zero and `exponent` is
+    /// negative or if `self` is negative and `exponent` is not an integer,
+    /// see [`FixedDecimal::checked_powd`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI64;
+    /// # use std::str::FromStr;
+    /// #
+    /// let a = FixedDecimalI64::<4>::from_str("2").unwrap();
+    /// let half = FixedDecimalI64::<4>::from_str("0.5").unwrap();
+    /// assert_eq!(a.powd(half).to_string(), "1.4142");
+    /// assert_eq!(a.powd(-a).to_string(), "0.2500");
+    /// ```
+    #[must_use]
+    pub fn powd(self, exponent: Self) -> Self {
+        let (_, fraction) = exponent.into_parts();
+        assert!(
+            self.0.is_positive() || fraction.widen().is_zero(),
+            "attempt to raise a negative number to a fractional power"
+        );
+        assert!(
+            exponent.0.is_positive() || !self.0.widen().is_zero(),
+            "attempt to divide by zero"
+        );
+        self.checked_powd(exponent)
+            .expect("attempt to multiply with overflow")
+    }
+
+    /// Checked decimal power.
+    /// Returns `None` if the result overflows, if `self` is zero and
+    /// `exponent` is negative or if `self` is negative and `exponent` is not
+    /// an integer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI64;
+    /// # use std::str::FromStr;
+    /// #
+    /// let a = FixedDecimalI64::<4>::from_str("-8").unwrap();
+    /// let third = FixedDecimalI64::<4>::from_str("0.3333").unwrap();
+    /// assert_eq!(a.checked_powd(FixedDecimalI64::from_int(3)).unwrap().to_string(), "-512.0000");
+    /// assert_eq!(a.checked_powd(third), None);
+    /// ```
+    #[must_use]
+    pub fn checked_powd(self, exponent: Self) -> Option<Self> {
+        let (integer, fraction) = exponent.into_parts();
+        if fraction.widen().is_zero() {
+            // Bigger exponents overflow or vanish unless `|self|` is one, so
+            // only the parity matters beyond `u64::MAX`.
+            let n: u128 = integer.widen().try_into().ok()?;
+            let n = u64::try_from(n).unwrap_or(u64::MAX - u64::from(n & 1 == 0));
+            return self.checked_pow_int(n, !exponent.0.is_positive());
+        }
+        let base = Extended::from_fixed(self);
+        let power = if base.is_zero() {
+            if !exponent.0.is_positive() {
+                return None;
+            }
+            Extended::zero()
+        } else {
+            base.ln()?.mul(&Extended::from_fixed(exponent)).exp()?
+        };
+        power.to_fixed(RoundingStrategy::default())
+    }
+
+    /// Square root rounded to the scale `E` with `strategy`.
+    /// Returns `None` if `self` is negative or if the result overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::{FixedDecimalI64, RoundingStrategy};
+    /// # use std::str::FromStr;
+    /// #
+    /// let a = FixedDecimalI64::<4>::from_str("2").unwrap();
+    /// assert_eq!(a.sqrt(RoundingStrategy::MidpointNearestEven).unwrap().to_string(), "1.4142");
+    /// assert_eq!(a.sqrt(RoundingStrategy::ToPositiveInfinity).unwrap().to_string(), "1.4143");
+    /// assert_eq!((-a).sqrt(RoundingStrategy::MidpointNearestEven), None);
+    /// ```
+    #[must_use]
+    pub fn sqrt(self, strategy: RoundingStrategy) -> Option<Self> {
+        self.nth_root(2, strategy)
+    }
+
+    /// `n`th root rounded to the scale `E` with `strategy`.
+    /// Returns `None` if `self` is negative, if `n` is zero or if the result
+    /// overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::{FixedDecimalI64, RoundingStrategy};
+    /// # use std::str::FromStr;
+    /// #
+    /// let a = FixedDecimalI64::<4>::from_str("27").unwrap();
+    /// assert_eq!(a.nth_root(3, RoundingStrategy::ToZero).unwrap().to_string(), "3.0000");
+    /// let a = FixedDecimalI64::<4>::from_str("0.5").unwrap();
+    /// assert_eq!(a.nth_root(5, RoundingStrategy::ToZero).unwrap().to_string(), "0.8705");
+    /// ```
+    #[must_use]
+    pub fn nth_root(self, n: u32, strategy: RoundingStrategy) -> Option<Self> {
+        if !self.0.is_positive() || n == 0 {
+            return None;
+        }
+        let mantissa = BigUint::from_u128(self.0.widen().try_into().ok()?);
+        let one = BigUint::pow10(E.into());
+        if mantissa.is_zero() || mantissa == one {
+            return Some(self);
+        }
+        // A root of `m / 10^E` other than one is rational only if `2^n` is at
+        // most `m` or `10^E`. Beyond that it never lies on a rounding boundary
+        // and extended precision rounds it correctly, without building a
+        // radicand of `E * n` digits.
+        if u64::from(n) >= mantissa.bits().max(one.bits()) {
+            return Extended::from_fixed(self)
+                .ln()?
+                .div(&Extended::from_u128(n.into()))?
+                .exp()?
+                .to_fixed(strategy);
+        }
+        // `root(m / 10^E) * 10^E = root(m * 10^(E * (n - 1)))`, at least
+        // `10^((digits(m) - 1 + E * (n - 1)) / n)` which must fit before the
+        // radicand gets built.
+        let digits = u64::from(mantissa.digits() - 1) + u64::from(E) * u64::from(n - 1);
+        let lower_bound = rounding::pow10(u32::try_from(digits / u64::from(n)).ok()?)?;
+        T::narrow(false, lower_bound)?;
+        let radicand = mantissa.mul(&BigUint::pow10(u32::from(E).checked_mul(n - 1)?));
+        let (root, exact) = radicand.nth_root(n);
+        // The discarded part is compared against half an unit through
+        // `(2 * root + 1)^n` against `2^n * radicand`, which never tie.
+        let remainder = (!exact).then(|| {
+            radicand
+                .shl(n.into())
+                .cmp(&root.shl(1).add(&BigUint::from_u128(1)).pow(n.into()))
+        });
+        let root = T::Wide::try_from(root.to_u128()?).ok()?;
+        rounding::round_quotient(root, remainder, false, strategy)
+            .and_then(|root| T::narrow(false, root))
+            .map(Self)
+    }
+
+    /// Natural exponential `e^self`.
+    ///
+    /// The result is computed with extended precision and rounded once with
+    /// [`RoundingStrategy::MidpointNearestEven`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result overflows, see [`FixedDecimal::checked_exp`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI64;
+    /// # use std::str::FromStr;
+    /// #
+    /// let rate = FixedDecimalI64::<8>::from_str("0.05").unwrap();
+    /// assert_eq!(rate.exp().to_string(), "1.05127110");
+    /// assert_eq!((-rate).exp().to_string(), "0.95122942");
+    /// ```
+    #[must_use]
+    pub fn exp(self) -> Self {
+        self.checked_exp()
+            .expect("attempt to exponentiate with overflow")
+    }
+
+    /// Checked natural exponential.
+    /// Returns `None` if the result overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI32;
+    /// #
+    /// assert_eq!(FixedDecimalI32::<2>::from_int(1).checked_exp().unwrap().to_string(), "2.72");
+    /// assert_eq!(FixedDecimalI32::<2>::from_int(20).checked_exp(), None);
+    /// ```
+    #[must_use]
+    pub fn checked_exp(self) -> Option<Self> {
+        Extended::from_fixed(self)
+            .exp()?
+            .to_fixed(RoundingStrategy::default())
+    }
+
+    /// Natural logarithm.
+    ///
+    /// The result is computed with extended precision and rounded once with
+    /// [`RoundingStrategy::MidpointNearestEven`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not positive or if the result overflows, see
+    /// [`FixedDecimal::checked_ln`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI64;
+    /// # use std::str::FromStr;
+    /// #
+    /// let growth = FixedDecimalI64::<8>::from_str("1.05").unwrap();
+    /// assert_eq!(growth.ln().to_string(), "0.04879016");
+    /// ```
+    #[must_use]
+    pub fn ln(self) -> Self {
+        assert!(
+            self.is_valid_log_argument(),
+            "attempt to take the logarithm of a non-positive number"
+        );
+        self.checked_ln()
+            .expect("attempt to take the logarithm with overflow")
+    }
+
+    /// Checked natural logarithm.
+    /// Returns `None` if `self` is not positive or if the result overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI32;
+    /// #
+    /// assert_eq!(FixedDecimalI32::<2>::from_int(10).checked_ln().unwrap().to_string(), "2.30");
+    /// assert_eq!(FixedDecimalI32::<2>::from_int(0).checked_ln(), None);
+    /// ```
+    #[must_use]
+    pub fn checked_ln(self) -> Option<Self> {
+        Extended::from_fixed(self)
+            .ln()?
+            .to_fixed(RoundingStrategy::default())
+    }
+
+    /// Base 10 logarithm.
+    ///
+    /// The result is computed with extended precision and rounded once with
+    /// [`RoundingStrategy::MidpointNearestEven`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not positive or if the result overflows, see
+    /// [`FixedDecimal::checked_log10`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI64;
+    /// # use std::str::FromStr;
+    /// #
+    /// let a = FixedDecimalI64::<4>::from_str("1000").unwrap();
+    /// assert_eq!(a.log10().to_string(), "3.0000");
+    /// let a = FixedDecimalI64::<4>::from_str("2").unwrap();
+    /// assert_eq!(a.log10().to_string(), "0.3010");
+    /// ```
+    #[must_use]
+    pub fn log10(self) -> Self {
+        assert!(
+            self.is_valid_log_argument(),
+            "attempt to take the logarithm of a non-positive number"
+        );
+        self.checked_log10()
+            .expect("attempt to take the logarithm with overflow")
+    }
+
+    /// Checked base 10 logarithm.
+    /// Returns `None` if `self` is not positive or if the result overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI32;
+    /// #
+    /// assert_eq!(FixedDecimalI32::<2>::new(1).checked_log10().unwrap().to_string(), "-2.00");
+    /// assert_eq!(FixedDecimalI32::<2>::new(-1).checked_log10(), None);
+    /// ```
+    #[must_use]
+    pub fn checked_log10(self) -> Option<Self> {
+        Extended::from_fixed(self)
+            .ln()?
+            .div(&Extended::ln10())?
+            .to_fixed(RoundingStrategy::default())
+    }
+
+    /// Logarithm in the given `base`.
+    ///
+    /// The result is computed with extended precision and rounded once with
+    /// [`RoundingStrategy::MidpointNearestEven`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` or `base` is not positive, if `base` is one or if
+    /// the result overflows, see [`FixedDecimal::checked_log`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI64;
+    /// # use std::str::FromStr;
+    /// #
+    /// let a = FixedDecimalI64::<4>::from_str("8").unwrap();
+    /// let base = FixedDecimalI64::<4>::from_str("2").unwrap();
+    /// assert_eq!(a.log(base).to_string(), "3.0000");
+    /// ```
+    #[must_use]
+    pub fn log(self, base: Self) -> Self {
+        assert!(
+            self.is_valid_log_argument(),
+            "attempt to take the logarithm of a non-positive number"
+        );
+        assert!(
+            base.is_valid_log_argument()
+                && !Extended::from_fixed(base)
+                    .ln()
+                    .is_some_and(|ln| ln.is_zero()),
+            "attempt to take the logarithm with an invalid base"
+        );
+        self.checked_log(base)
+            .expect("attempt to take the logarithm with overflow")
+    }
+
+    /// Checked logarithm in the given `base`.
+    /// Returns `None` if `self` or `base` is not positive, if `base` is one
+    /// or if the result overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::FixedDecimalI64;
+    /// # use std::str::FromStr;
+    /// #
+    /// let a = FixedDecimalI64::<4>::from_str("1.5").unwrap();
+    /// assert_eq!(a.checked_log(a).unwrap().to_string(), "1.0000");
+    /// assert_eq!(a.checked_log(FixedDecimalI64::ONE), None);
+    /// ```
+    #[must_use]
+    pub fn checked_log(self, base: Self) -> Option<Self> {
+        Extended::from_fixed(self)
+            .ln()?
+            .div(&Extended::from_fixed(base).ln()?)?
+            .to_fixed(RoundingStrategy::default())
+    }
+
+    fn is_valid_log_argument(self) -> bool {
+        self.0.is_positive() && !self.0.widen().is_zero()
+    }
+}
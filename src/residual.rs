@@ -0,0 +1,225 @@
+use crate::{
+    ext_num_traits::{self, Sign, WideUnsigned},
+    rounding, FixedDecimal, RoundingStrategy,
+};
+
+/// Sign and magnitude of `a - b` for magnitudes of the sign `negative`.
+fn signed_difference<W: WideUnsigned>(negative: bool, a: W, b: W) -> (bool, W) {
+    if a >= b {
+        (
+            negative,
+            a.checked_sub(&b).expect("a is not smaller than b"),
+        )
+    } else {
+        (!negative, b.checked_sub(&a).expect("b is bigger than a"))
+    }
+}
+
+/// Rounding operations that also return what they dropped, the residual
+/// being negative whenever rounding moved away from zero, hence only for
+/// signed backings.
+#[expect(private_bounds)]
+impl<T, const E: u8> FixedDecimal<T, E>
+where
+    T: ext_num_traits::Widen + num_traits::Signed,
+{
+    /// Rounds to `places` decimal places like [`FixedDecimal::round_dp`],
+    /// also returning the residual such that `rounded + residual == self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::{FixedDecimalI64, RoundingStrategy};
+    /// # use std::str::FromStr;
+    /// #
+    /// let a = FixedDecimalI64::<4>::from_str("1.2350").unwrap();
+    /// let (rounded, residual) = a.round_with_residual(2, RoundingStrategy::MidpointNearestEven);
+    /// assert_eq!(rounded.to_string(), "1.2400");
+    /// assert_eq!(residual.to_string(), "-0.0050");
+    /// assert_eq!(rounded + residual, a);
+    /// ```
+    #[must_use]
+    pub fn round_with_residual(self, places: u8, strategy: RoundingStrategy) -> (Self, Self) {
+        self.checked_round_with_residual(places, strategy)
+            .expect("attempt to round with overflow")
+    }
+
+    /// Rounds to `places` decimal places like
+    /// [`FixedDecimal::checked_round_dp`], also returning the residual such
+    /// that `rounded + residual == self`.
+    /// Returns `None` if the result overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::{FixedDecimalI8, RoundingStrategy};
+    /// #
+    /// let a = FixedDecimalI8::<1>::new(-125);
+    /// let (rounded, residual) = a.checked_round_with_residual(0, RoundingStrategy::ToZero).unwrap();
+    /// assert_eq!((rounded.to_string(), residual.to_string()), ("-12.0".into(), "-0.5".into()));
+    /// assert_eq!(a.checked_round_with_residual(0, RoundingStrategy::AwayFromZero), None);
+    /// ```
+    #[must_use]
+    pub fn checked_round_with_residual(
+        self,
+        places: u8,
+        strategy: RoundingStrategy,
+    ) -> Option<(Self, Self)> {
+        let rounded = self.checked_round_dp(places, strategy)?;
+        let (negative, residual) = signed_difference(
+            !Sign::is_positive(&self.0),
+            self.0.widen(),
+            rounded.0.widen(),
+        );
+        // The rounded magnitude is a multiple of ten so it is never the one
+        // magnitude only fitting a negative value, the residual fits.
+        let residual = T::narrow(negative, residual).expect("residual fits the backing type");
+        Some((rounded, Self(residual)))
+    }
+
+    /// Converts to another scale like [`FixedDecimal::rescale`], also
+    /// returning the residual at the original scale such that
+    /// `rounded + residual == self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result or the residual overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::{FixedDecimalI64, RoundingStrategy};
+    /// # use std::str::FromStr;
+    /// #
+    /// let a = FixedDecimalI64::<4>::from_str("-10.0049").unwrap();
+    /// let (rounded, residual) = a.rescale_with_residual::<2>(RoundingStrategy::default());
+    /// assert_eq!(rounded.to_string(), "-10.00");
+    /// assert_eq!(residual.to_string(), "-0.0049");
+    /// ```
+    #[must_use]
+    pub fn rescale_with_residual<const TARGET_SCALE: u8>(
+        self,
+        strategy: RoundingStrategy,
+    ) -> (FixedDecimal<T, TARGET_SCALE>, Self) {
+        self.checked_rescale_with_residual(strategy)
+            .expect("attempt to rescale with overflow")
+    }
+
+    /// Converts to another scale like [`FixedDecimal::checked_rescale`], also
+    /// returning the residual at the original scale such that
+    /// `rounded + residual == self`.
+    /// Returns `None` if the result or the residual overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::{FixedDecimalI8, RoundingStrategy};
+    /// #
+    /// let a = FixedDecimalI8::<1>::new(15);
+    /// let (rounded, residual) = a.checked_rescale_with_residual::<0>(RoundingStrategy::default()).unwrap();
+    /// assert_eq!((rounded.to_string(), residual.to_string()), ("2".into(), "-0.5".into()));
+    /// assert_eq!(a.checked_rescale_with_residual::<2>(RoundingStrategy::default()), None);
+    /// ```
+    #[must_use]
+    pub fn checked_rescale_with_residual<const TARGET_SCALE: u8>(
+        self,
+        strategy: RoundingStrategy,
+    ) -> Option<(FixedDecimal<T, TARGET_SCALE>, Self)> {
+        let rounded = self.checked_rescale::<TARGET_SCALE>(strategy)?;
+        let Some(exp) = E.checked_sub(TARGET_SCALE) else {
+            return Some((rounded, Self(T::zero())));
+        };
+        let rounded_back = rounding::mul_pow10(rounded.0.widen(), exp.into())?;
+        let (negative, residual) =
+            signed_difference(!Sign::is_positive(&self.0), self.0.widen(), rounded_back);
+        Some((rounded, Self(T::narrow(negative, residual)?)))
+    }
+
+    /// Divides like [`FixedDecimal::div_with_rounding`], also returning the
+    /// residual at the scale `R` such that `rounded * rhs + residual == self`.
+    ///
+    /// `R` must be at least `2 * E`, the scale of `rounded * rhs`, so the
+    /// residual is always exact.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero or if the result or the residual overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::{FixedDecimalI64, RoundingStrategy};
+    /// # use std::str::FromStr;
+    /// #
+    /// let total = FixedDecimalI64::<2>::from_str("10.00").unwrap();
+    /// let qty = FixedDecimalI64::<2>::from_str("3").unwrap();
+    /// let (unit, residual): (_, FixedDecimalI64<4>) =
+    ///     total.div_rem_rounded(qty, RoundingStrategy::default());
+    /// assert_eq!(unit.to_string(), "3.33");
+    /// assert_eq!(residual.to_string(), "0.0100");
+    /// ```
+    #[must_use]
+    pub fn div_rem_rounded<const R: u8>(
+        self,
+        rhs: Self,
+        strategy: RoundingStrategy,
+    ) -> (Self, FixedDecimal<T, R>) {
+        assert!(!rhs.0.is_zero(), "attempt to divide by zero");
+        self.checked_div_rem_rounded(rhs, strategy)
+            .expect("attempt to divide with overflow")
+    }
+
+    /// Divides like [`FixedDecimal::checked_div_with_rounding`], also
+    /// returning the residual at the scale `R` such that
+    /// `rounded * rhs + residual == self`.
+    /// Returns `None` if `rhs` is zero or if the result or the residual
+    /// overflows.
+    ///
+    /// `R` must be at least `2 * E`, the scale of `rounded * rhs`, so the
+    /// residual is always exact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_fixed_decimal::{FixedDecimalI8, RoundingStrategy};
+    /// #
+    /// let a = FixedDecimalI8::<1>::new(-20);
+    /// let b = FixedDecimalI8::<1>::new(30);
+    /// let (floor, residual) = a
+    ///     .checked_div_rem_rounded::<2>(b, RoundingStrategy::ToNegativeInfinity)
+    ///     .unwrap();
+    /// assert_eq!((floor.to_string(), residual.to_string()), ("-0.7".into(), "0.10".into()));
+    /// assert_eq!(a.checked_div_rem_rounded::<2>(FixedDecimalI8::new(0), RoundingStrategy::default()), None);
+    /// ```
+    #[must_use]
+    pub fn checked_div_rem_rounded<const R: u8>(
+        self,
+        rhs: Self,
+        strategy: RoundingStrategy,
+    ) -> Option<(Self, FixedDecimal<T, R>)> {
+        const {
+            assert!(
+                R as u16 >= 2 * E as u16,
+                "the residual scale must be at least twice the scale"
+            );
+        }
+        let negative = Sign::is_positive(&self.0) != Sign::is_positive(&rhs.0);
+        let divisor = rhs.0.widen();
+        let dividend = rounding::mul_pow10(self.0.widen(), E.into())?;
+        let quotient = rounding::div_rounded(dividend, divisor, negative, strategy)?;
+        let rounded = T::narrow(negative, quotient)?;
+        // `self - rounded * rhs` at the scale `2 * E`.
+        let (residual_negative, residual) = signed_difference(
+            !Sign::is_positive(&self.0),
+            dividend,
+            num_traits::CheckedMul::checked_mul(&quotient, &divisor)?,
+        );
+        let residual = rounding::mul_pow10(residual, u32::from(R) - 2 * u32::from(E))?;
+        let residual = T::narrow(residual_negative, residual)?;
+        Some((Self(rounded), FixedDecimal(residual)))
+    }
+}
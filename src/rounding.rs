@@ -0,0 +1,165 @@
+use std::cmp::Ordering;
+
+use crate::{bignum::BigUint, ext_num_traits::WideUnsigned};
+
+/// Strategy used to drop the digits that do not fit in the scale of a result.
+///
+/// # Example
+///
+/// ```
+/// # use rust_fixed_decimal::{FixedDecimalI128, RoundingStrategy};
+/// # use std::str::FromStr;
+/// #
+/// let a = FixedDecimalI128::<2>::from_str("0.25").unwrap();
+/// let b = FixedDecimalI128::<2>::from_str("0.50").unwrap();
+///
+/// let even = a.mul_with_rounding(b, RoundingStrategy::MidpointNearestEven);
+/// let up = a.mul_with_rounding(b, RoundingStrategy::MidpointAwayFromZero);
+/// assert_eq!(even.to_string(), "0.12");
+/// assert_eq!(up.to_string(), "0.13");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum RoundingStrategy {
+    /// Rounds to the nearest value, ties go to the even neighbour, also known
+    /// as "half even" or "banker's rounding".
+    /// e.g. `6.5 -> 6`, `7.5 -> 8`, `-6.5 -> -6`
+    #[default]
+    MidpointNearestEven,
+    /// Rounds to the nearest value, ties go away from zero, also known as
+    /// "half up".
+    /// e.g. `6.5 -> 7`, `-6.5 -> -7`
+    MidpointAwayFromZero,
+    /// Rounds to the nearest value, ties go toward zero, also known as
+    /// "half down".
+    /// e.g. `6.5 -> 6`, `-6.5 -> -6`
+    MidpointTowardZero,
+    /// Always rounds toward zero (truncation).
+    /// e.g. `6.8 -> 6`, `-6.8 -> -6`
+    ToZero,
+    /// Always rounds away from zero.
+    /// e.g. `6.2 -> 7`, `-6.2 -> -7`
+    AwayFromZero,
+    /// Always rounds down (floor).
+    /// e.g. `6.8 -> 6`, `-6.2 -> -7`
+    ToNegativeInfinity,
+    /// Always rounds up (ceiling).
+    /// e.g. `6.2 -> 7`, `-6.8 -> -6`
+    ToPositiveInfinity,
+}
+
+impl RoundingStrategy {
+    /// Whether a truncated magnitude must be moved one unit away from zero.
+    ///
+    /// `remainder` is `None` when nothing was discarded, otherwise the
+    /// discarded part compared against half of an unit.
+    pub(crate) fn increments(self, negative: bool, odd: bool, remainder: Option<Ordering>) -> bool {
+        let Some(half) = remainder else {
+            return false;
+        };
+        match self {
+            Self::MidpointNearestEven => {
+                half == Ordering::Greater || (half == Ordering::Equal && odd)
+            }
+            Self::MidpointAwayFromZero => half != Ordering::Less,
+            Self::MidpointTowardZero => half == Ordering::Greater,
+            Self::ToZero => false,
+            Self::AwayFromZero => true,
+            Self::ToNegativeInfinity => negative,
+            Self::ToPositiveInfinity => !negative,
+        }
+    }
+}
+
+fn two<W: WideUnsigned>() -> W {
+    W::ONE + W::ONE
+}
+
+pub(crate) fn round_quotient<W: WideUnsigned>(
+    quotient: W,
+    remainder: Option<Ordering>,
+    negative: bool,
+    strategy: RoundingStrategy,
+) -> Option<W> {
+    let odd = quotient % two() != W::ZERO;
+    if strategy.increments(negative, odd, remainder) {
+        quotient.checked_add(&W::ONE)
+    } else {
+        Some(quotient)
+    }
+}
+
+/// `10^exp`, or `None` if it does not fit in `W`.
+pub(crate) fn pow10<W: WideUnsigned>(exp: u32) -> Option<W> {
+    num_traits::checked_pow(W::TEN, exp.try_into().ok()?)
+}
+
+/// `n * 10^exp`, or `None` on overflow.
+pub(crate) fn mul_pow10<W: WideUnsigned>(n: W, exp: u32) -> Option<W> {
+    if n == W::ZERO {
+        return Some(W::ZERO);
+    }
+    pow10::<W>(exp)?.checked_mul(&n)
+}
+
+/// `n % 10^exp`, the divisor may not fit in `W`.
+pub(crate) fn rem_pow10<W: WideUnsigned>(n: W, exp: u32) -> W {
+    pow10::<W>(exp).map_or(n, |d| n % d)
+}
+
+/// Rounded `n / d` of magnitudes, `negative` being the sign of the result.
+///
+/// Returns `None` if `d` is zero or if rounding overflows `W`.
+pub(crate) fn div_rounded<W: WideUnsigned>(
+    n: W,
+    d: W,
+    negative: bool,
+    strategy: RoundingStrategy,
+) -> Option<W> {
+    if d == W::ZERO {
+        return None;
+    }
+    let remainder = n % d;
+    let half = (remainder != W::ZERO).then(|| remainder.cmp(&(d - remainder)));
+    round_quotient(n / d, half, negative, strategy)
+}
+
+/// Rounded `n / d` of magnitudes too big for any `W`, `negative` being the
+/// sign of the result.
+///
+/// Returns `None` if `d` is zero or if the result does not fit in `W`.
+pub(crate) fn big_div_rounded<W: WideUnsigned>(
+    n: &BigUint,
+    d: &BigUint,
+    negative: bool,
+    strategy: RoundingStrategy,
+) -> Option<W> {
+    if d.is_zero() {
+        return None;
+    }
+    let (quotient, remainder) = n.divrem(d);
+    let half = (!remainder.is_zero()).then(|| remainder.shl(1).cmp(d));
+    let quotient = W::try_from(quotient.to_u128()?).ok()?;
+    round_quotient(quotient, half, negative, strategy)
+}
+
+/// Rounded `n / 10^exp` of magnitudes, `negative` being the sign of the result.
+///
+/// Unlike [`div_rounded`] the divisor may not fit in `W`.
+pub(crate) fn div_pow10_rounded<W: WideUnsigned>(
+    n: W,
+    exp: u32,
+    negative: bool,
+    strategy: RoundingStrategy,
+) -> Option<W> {
+    if let Some(d) = pow10::<W>(exp) {
+        return div_rounded(n, d, negative, strategy);
+    }
+    // `10^exp` is bigger than any `W`, so the whole `n` is discarded and
+    // it only matters how it compares to `5 * 10^(exp - 1)`.
+    let half = (n != W::ZERO).then(|| {
+        pow10::<W>(exp - 1)
+            .and_then(|p| p.checked_mul(&(two::<W>() + two() + W::ONE)))
+            .map_or(Ordering::Less, |half| n.cmp(&half))
+    });
+    round_quotient(W::ZERO, half, negative, strategy)
+}
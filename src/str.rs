@@ -91,6 +91,7 @@ pub enum ParseError {
     PosOverflow,
     NegOverflow,
     Underflow,
+    InvalidRadix,
 }
 
 pub(crate) fn parse_str_radix_10_exact<T, const SCALE: u8>(
@@ -149,7 +150,7 @@ where
         }
         None => Err(ParseError::Empty),
     }?;
-    let pack = |v: T| Ok(v).map(FixedDecimal::<T, SCALE>::new);
+    let pack = |v: T| Ok(FixedDecimal::<T, SCALE>::new(v));
     let overflow_err = || {
         if is_negative {
             ParseError::NegOverflow
@@ -192,7 +193,7 @@ where
     match digits.next() {
         None => pack(acc),
         Some(c) => {
-            if digits.chain(std::iter::once(c)).all(|d| d.is_digit(10)) {
+            if digits.chain(std::iter::once(c)).all(|d| d.is_ascii_digit()) {
                 Err(ParseError::Underflow)
             } else {
                 Err(ParseError::InvalidDigit)
@@ -0,0 +1,264 @@
+use std::ops;
+
+use crate::ext_num_traits;
+
+/// Fixed width 256 bits unsigned integer, the wide type of the 128 bits
+/// backings so the product of two magnitudes never overflows.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct U256 {
+    // Declared high first so the derived ordering is numeric.
+    high: u128,
+    low: u128,
+}
+
+/// Error of a [`U256`] that does not fit in an `u128`.
+#[derive(Debug)]
+pub(crate) struct TryFromU256Error;
+
+const LOW_64: u128 = u64::MAX as u128;
+
+/// Full 256 bits product of two `u128` as `(high, low)`.
+fn widening_mul(a: u128, b: u128) -> (u128, u128) {
+    let (a_low, a_high) = (a & LOW_64, a >> 64);
+    let (b_low, b_high) = (b & LOW_64, b >> 64);
+    let low_low = a_low * b_low;
+    let low_high = a_low * b_high;
+    let high_low = a_high * b_low;
+    let high_high = a_high * b_high;
+    // At most three times `2^64 - 1`, it cannot overflow.
+    let middle = (low_low >> 64) + (low_high & LOW_64) + (high_low & LOW_64);
+    let low = (low_low & LOW_64) | middle << 64;
+    let high = high_high + (low_high >> 64) + (high_low >> 64) + (middle >> 64);
+    (high, low)
+}
+
+impl U256 {
+    const fn from_u128(n: u128) -> Self {
+        Self { high: 0, low: n }
+    }
+
+    fn leading_zeros(self) -> u32 {
+        if self.high == 0 {
+            128 + self.low.leading_zeros()
+        } else {
+            self.high.leading_zeros()
+        }
+    }
+
+    /// `self << bits`, `bits` must be smaller than 256.
+    fn shl(self, bits: u32) -> Self {
+        match bits {
+            0 => self,
+            1..=127 => Self {
+                high: self.high << bits | self.low >> (128 - bits),
+                low: self.low << bits,
+            },
+            _ => Self {
+                high: self.low << (bits - 128),
+                low: 0,
+            },
+        }
+    }
+
+    /// `self >> bits`, `bits` must be smaller than 256.
+    fn shr(self, bits: u32) -> Self {
+        match bits {
+            0 => self,
+            1..=127 => Self {
+                high: self.high >> bits,
+                low: self.low >> bits | self.high << (128 - bits),
+            },
+            _ => Self {
+                high: 0,
+                low: self.high >> (bits - 128),
+            },
+        }
+    }
+
+    /// Quotient and remainder of `self / rhs`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    fn div_rem(self, rhs: Self) -> (Self, Self) {
+        assert!(rhs != Self::default(), "attempt to divide by zero");
+        if self.high == 0 && rhs.high == 0 {
+            return (
+                Self::from_u128(self.low / rhs.low),
+                Self::from_u128(self.low % rhs.low),
+            );
+        }
+        if rhs.high == 0 && rhs.low <= LOW_64 {
+            // Short division by 64 bits digits, every partial dividend is
+            // smaller than `rhs * 2^64` so it fits an `u128`.
+            let d = rhs.low;
+            let (high, rest) = (self.high / d, self.high % d);
+            let t = rest << 64 | self.low >> 64;
+            let (middle, rest) = (t / d, t % d);
+            let t = rest << 64 | (self.low & LOW_64);
+            let (low, rest) = (t / d, t % d);
+            return (
+                Self {
+                    high,
+                    low: middle << 64 | low,
+                },
+                Self::from_u128(rest),
+            );
+        }
+        if self < rhs {
+            return (Self::default(), self);
+        }
+        // Shift and subtract, one quotient bit per step.
+        let shift = rhs.leading_zeros() - self.leading_zeros();
+        let mut divisor = rhs.shl(shift);
+        let (mut quotient, mut remainder) = (Self::default(), self);
+        for _ in 0..=shift {
+            quotient = quotient.shl(1);
+            if remainder >= divisor {
+                remainder = remainder - divisor;
+                quotient.low |= 1;
+            }
+            divisor = divisor.shr(1);
+        }
+        (quotient, remainder)
+    }
+}
+
+impl From<u8> for U256 {
+    fn from(n: u8) -> Self {
+        Self::from_u128(n.into())
+    }
+}
+
+impl From<u128> for U256 {
+    fn from(n: u128) -> Self {
+        Self::from_u128(n)
+    }
+}
+
+impl TryFrom<U256> for u128 {
+    type Error = TryFromU256Error;
+
+    fn try_from(n: U256) -> Result<Self, Self::Error> {
+        if n.high == 0 {
+            Ok(n.low)
+        } else {
+            Err(TryFromU256Error)
+        }
+    }
+}
+
+impl ops::Add for U256 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        num_traits::CheckedAdd::checked_add(&self, &rhs).expect("attempt to add with overflow")
+    }
+}
+
+impl ops::Sub for U256 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        num_traits::CheckedSub::checked_sub(&self, &rhs).expect("attempt to subtract with overflow")
+    }
+}
+
+impl ops::Mul for U256 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        num_traits::CheckedMul::checked_mul(&self, &rhs).expect("attempt to multiply with overflow")
+    }
+}
+
+impl ops::Div for U256 {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.div_rem(rhs).0
+    }
+}
+
+impl ops::Rem for U256 {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.div_rem(rhs).1
+    }
+}
+
+impl num_traits::CheckedAdd for U256 {
+    fn checked_add(&self, v: &Self) -> Option<Self> {
+        let (low, carry) = self.low.overflowing_add(v.low);
+        let high = self.high.checked_add(v.high)?.checked_add(carry.into())?;
+        Some(Self { high, low })
+    }
+}
+
+impl num_traits::CheckedSub for U256 {
+    fn checked_sub(&self, v: &Self) -> Option<Self> {
+        let (low, borrow) = self.low.overflowing_sub(v.low);
+        let high = self.high.checked_sub(v.high)?.checked_sub(borrow.into())?;
+        Some(Self { high, low })
+    }
+}
+
+impl num_traits::CheckedMul for U256 {
+    fn checked_mul(&self, v: &Self) -> Option<Self> {
+        if self.high != 0 && v.high != 0 {
+            return None;
+        }
+        let (high, low) = widening_mul(self.low, v.low);
+        let cross = self
+            .high
+            .checked_mul(v.low)?
+            .checked_add(self.low.checked_mul(v.high)?)?;
+        let high = high.checked_add(cross)?;
+        Some(Self { high, low })
+    }
+}
+
+impl num_traits::WrappingMul for U256 {
+    fn wrapping_mul(&self, v: &Self) -> Self {
+        let (high, low) = widening_mul(self.low, v.low);
+        let high = high
+            .wrapping_add(self.high.wrapping_mul(v.low))
+            .wrapping_add(self.low.wrapping_mul(v.high));
+        Self { high, low }
+    }
+}
+
+impl num_traits::Zero for U256 {
+    fn zero() -> Self {
+        Self::default()
+    }
+
+    fn is_zero(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl num_traits::ConstZero for U256 {
+    const ZERO: Self = Self::from_u128(0);
+}
+
+impl num_traits::One for U256 {
+    fn one() -> Self {
+        Self::from_u128(1)
+    }
+}
+
+impl num_traits::ConstOne for U256 {
+    const ONE: Self = Self::from_u128(1);
+}
+
+impl ext_num_traits::ConstTen for U256 {
+    const TEN: Self = Self::from_u128(10);
+}
+
+impl ext_num_traits::Truncate<u128> for U256 {
+    fn truncate(self) -> u128 {
+        self.low
+    }
+}
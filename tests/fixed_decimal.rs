@@ -1,9 +1,16 @@
 // Most of test case are based on rust_decimal test cases
 use num_traits::*;
 use proptest::prelude::*;
-use std::str::FromStr;
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
 
-use rust_fixed_decimal::{FixedDecimal, FixedDecimalI128, FixedDecimalI8, FixedDecimalU128};
+use rust_fixed_decimal::{
+    ArithmeticError, FixedDecimal, FixedDecimalI128, FixedDecimalI32, FixedDecimalI64,
+    FixedDecimalI8, FixedDecimalU128, FixedDecimalU64, FixedDecimalU8, RoundingStrategy,
+};
 
 // Consts
 #[test]
@@ -18,18 +25,18 @@ fn it_consts_bounds() {
 fn it_consts_one() {
     assert_eq!(
         FixedDecimalI128::<0>::ONE,
-        FixedDecimalI128::from_str("1").unwrap()
+        FixedDecimalI128::<0>::from_str("1").unwrap()
     );
     assert_eq!(
         FixedDecimalI128::<7>::ONE,
-        FixedDecimalI128::from_str("1").unwrap()
+        FixedDecimalI128::<7>::from_str("1").unwrap()
     );
 }
 
 #[test]
 fn it_zero() {
-    assert_eq!(FixedDecimalI128::<0>::zero(), FixedDecimalI128::new(0));
-    assert_eq!(FixedDecimalI128::<7>::zero(), FixedDecimalI128::new(0));
+    assert_eq!(FixedDecimalI128::<0>::zero(), FixedDecimalI128::<0>::new(0));
+    assert_eq!(FixedDecimalI128::<7>::zero(), FixedDecimalI128::<7>::new(0));
 }
 
 #[test]
@@ -469,6 +476,1998 @@ fn it_can_mult_simple() {
     assert_eq!(one, FixedDecimalI128::<2>::from_str("3").unwrap());
 }
 
+#[test]
+fn it_can_mult_decimals() {
+    let a = FixedDecimalI128::<2>::from_str("1.25").unwrap();
+    let b = FixedDecimalI128::<2>::from_str("-2.50").unwrap();
+    assert_eq!((a * b).to_string(), "-3.12");
+    assert_eq!((a * a).to_string(), "1.56");
+    assert_eq!((b * b).to_string(), "6.25");
+    assert_eq!(a * FixedDecimalI128::<2>::ONE, a);
+}
+
+#[test]
+fn it_can_mult_with_rounding() {
+    let a = FixedDecimalI64::<2>::from_str("-0.25").unwrap();
+    let b = FixedDecimalI64::<2>::from_str("0.50").unwrap();
+    let cases = [
+        (RoundingStrategy::MidpointNearestEven, "-0.12"),
+        (RoundingStrategy::MidpointAwayFromZero, "-0.13"),
+        (RoundingStrategy::MidpointTowardZero, "-0.12"),
+        (RoundingStrategy::ToZero, "-0.12"),
+        (RoundingStrategy::AwayFromZero, "-0.13"),
+        (RoundingStrategy::ToNegativeInfinity, "-0.13"),
+        (RoundingStrategy::ToPositiveInfinity, "-0.12"),
+    ];
+    for (strategy, expected) in cases {
+        assert_eq!(a.mul_with_rounding(b, strategy).to_string(), expected);
+    }
+}
+
+#[test]
+fn it_mults_beyond_backing_digits() {
+    let tiny = FixedDecimalI128::<{ u8::MAX }>::new(7);
+    assert_eq!(tiny.checked_mul(tiny), Some(FixedDecimalI128::new(0)));
+    assert_eq!(
+        tiny.checked_mul_with_rounding(tiny, RoundingStrategy::AwayFromZero),
+        Some(FixedDecimalI128::new(1))
+    );
+}
+
+#[test]
+fn it_checks_mult_overflow() {
+    let a = FixedDecimalI8::<1>::new(100);
+    assert_eq!(a.checked_mul(a), None);
+    assert_eq!(
+        FixedDecimalI8::<0>::MIN.checked_mul(FixedDecimalI8::<0>::ONE),
+        Some(FixedDecimalI8::<0>::MIN)
+    );
+    assert_eq!(
+        FixedDecimalI8::<0>::MIN.checked_mul(-FixedDecimalI8::<0>::ONE),
+        None
+    );
+    let big = FixedDecimalU128::<2>::MAX;
+    assert_eq!(big.checked_mul(FixedDecimalU128::<2>::ONE), Some(big));
+}
+
+#[test]
+fn it_can_mult_128_bits_high_scale() {
+    // The mantissa products need more than 128 bits.
+    let a = FixedDecimalI128::<18>::from_str("12345678901234567890.123456789012345678").unwrap();
+    let b = FixedDecimalI128::<18>::from_str("-0.5").unwrap();
+    assert_eq!(
+        (a * b).to_string(),
+        "-6172839450617283945.061728394506172839"
+    );
+    let c = FixedDecimalI128::<18>::from_str("10000000000").unwrap();
+    assert_eq!(
+        (c * c).to_string(),
+        "100000000000000000000.000000000000000000"
+    );
+    assert_eq!(
+        FixedDecimalI128::<18>::MAX.checked_mul(FixedDecimalI128::ONE),
+        Some(FixedDecimalI128::<18>::MAX)
+    );
+    assert_eq!(
+        FixedDecimalI128::<18>::MIN.checked_mul(-FixedDecimalI128::<18>::ONE),
+        None
+    );
+    let d = |s| FixedDecimalU128::<38>::from_str(s).unwrap();
+    assert_eq!(d("3").checked_mul(d("0.5")), Some(d("1.5")));
+    assert_eq!(
+        FixedDecimalU128::<38>::MAX.checked_mul(FixedDecimalU128::<38>::MAX),
+        None
+    );
+}
+
+#[test]
+fn it_can_mult_into_other_scale() {
+    let price = FixedDecimalI64::<2>::from_str("-19.99").unwrap();
+    let qty = FixedDecimalI64::<3>::from_str("2.505").unwrap();
+
+    let exact: FixedDecimalI64<5> = price.mul_into(qty, RoundingStrategy::ToZero).unwrap();
+    assert_eq!(exact.to_string(), "-50.07495");
+    let wider: FixedDecimalI64<8> = price.mul_into(qty, RoundingStrategy::ToZero).unwrap();
+    assert_eq!(wider.to_string(), "-50.07495000");
+    let cents: FixedDecimalI64<2> = price
+        .mul_into(qty, RoundingStrategy::MidpointAwayFromZero)
+        .unwrap();
+    assert_eq!(cents.to_string(), "-50.07");
+    let floor: FixedDecimalI64<0> = price
+        .mul_into(qty, RoundingStrategy::ToNegativeInfinity)
+        .unwrap();
+    assert_eq!(floor.to_string(), "-51");
+}
+
+#[test]
+fn it_checks_mult_into_overflow() {
+    let a = FixedDecimalI8::<0>::new(10);
+    let b = FixedDecimalI8::<1>::new(100);
+    assert_eq!(
+        a.mul_into::<1, 0>(b, RoundingStrategy::default()),
+        Some(FixedDecimalI8::new(100))
+    );
+    assert_eq!(a.mul_into::<1, 1>(b, RoundingStrategy::default()), None);
+    assert_eq!(
+        a.mul_into::<1, { u8::MAX }>(FixedDecimalI8::new(0), RoundingStrategy::default()),
+        Some(FixedDecimalI8::new(0))
+    );
+}
+
+proptest! {
+    #[test]
+    fn mults_into_same_scale_as_mults(a in any::<i32>(), b in any::<i32>()) {
+        let a = FixedDecimalI64::<3>::new(a.into());
+        let b = FixedDecimalI64::<3>::new(b.into());
+        assert_eq!(a.mul_into::<3, 3>(b, RoundingStrategy::default()), a.checked_mul(b));
+    }
+}
+
+#[test]
+#[should_panic]
+fn it_panics_on_mult_overflow() {
+    let _ = FixedDecimalI8::<1>::MAX * FixedDecimalI8::<1>::MAX;
+}
+
+proptest! {
+    #[test]
+    fn mults_works_as_internal(a in any::<i32>(), b in any::<i32>()) {
+        let expected = (a as i128 * b as i128) as f64 / 1000.;
+        let result = FixedDecimalI64::<3>::new(a.into()).checked_mul(FixedDecimalI64::<3>::new(b.into()));
+        if let Some(result) = result {
+            assert!((result.mantissa() as f64 - expected).abs() <= 0.5 + expected.abs() * 1e-12);
+        } else {
+            assert!(expected.abs() >= i64::MAX as f64 - 1.);
+        }
+    }
+
+    #[test]
+    fn mults_128_bits_do_not_overflow_intermediate(a in any::<i32>(), b in any::<i32>()) {
+        let unit = 10i128.pow(18);
+        let result = FixedDecimalI128::<18>::new(a as i128 * unit) * FixedDecimalI128::<18>::new(b as i128 * unit);
+        assert_eq!(result.mantissa(), a as i128 * b as i128 * unit);
+    }
+}
+
+// Div
+
+#[test]
+fn it_can_div_simple() {
+    let a = FixedDecimalI128::<4>::from_str("10").unwrap();
+    let b = FixedDecimalI128::<4>::from_str("4").unwrap();
+    assert_eq!((a / b).to_string(), "2.5000");
+    assert_eq!((a / -b).to_string(), "-2.5000");
+    assert_eq!((b / a).to_string(), "0.4000");
+}
+
+#[test]
+fn it_can_div_repeating() {
+    let one = FixedDecimalI64::<4>::ONE;
+    let three = FixedDecimalI64::<4>::from_str("3").unwrap();
+    assert_eq!((one / three).to_string(), "0.3333");
+    assert_eq!(((one + one) / three).to_string(), "0.6667");
+    assert_eq!((-(one + one) / three).to_string(), "-0.6667");
+}
+
+#[test]
+fn it_can_div_with_rounding() {
+    let a = FixedDecimalI64::<1>::from_str("-0.5").unwrap();
+    let b = FixedDecimalI64::<1>::from_str("2").unwrap();
+    let cases = [
+        (RoundingStrategy::MidpointNearestEven, "-0.2"),
+        (RoundingStrategy::MidpointAwayFromZero, "-0.3"),
+        (RoundingStrategy::MidpointTowardZero, "-0.2"),
+        (RoundingStrategy::ToZero, "-0.2"),
+        (RoundingStrategy::AwayFromZero, "-0.3"),
+        (RoundingStrategy::ToNegativeInfinity, "-0.3"),
+        (RoundingStrategy::ToPositiveInfinity, "-0.2"),
+    ];
+    for (strategy, expected) in cases {
+        assert_eq!(a.div_with_rounding(b, strategy).to_string(), expected);
+    }
+}
+
+#[test]
+fn it_checks_div_overflow() {
+    let max = FixedDecimalI64::<8>::MAX;
+    let half = FixedDecimalI64::<8>::from_str("0.5").unwrap();
+    assert_eq!(max.checked_div(half), None);
+    assert_eq!(max.checked_div(FixedDecimalI64::<8>::ONE), Some(max));
+    assert_eq!(max.checked_div(FixedDecimalI64::<8>::new(0)), None);
+    assert_eq!(
+        FixedDecimalI8::<0>::MIN.checked_div(-FixedDecimalI8::<0>::ONE),
+        None
+    );
+}
+
+#[test]
+fn it_can_div_128_bits_high_scale() {
+    // The dividends scaled by `10^18` need more than 128 bits.
+    let a = FixedDecimalI128::<18>::from_str("10000000000000000000").unwrap();
+    let b = FixedDecimalI128::<18>::from_str("-0.3").unwrap();
+    assert_eq!(
+        (a / b).to_string(),
+        "-33333333333333333333.333333333333333333"
+    );
+    let b = FixedDecimalI128::<18>::from_str("3").unwrap();
+    assert_eq!(
+        (a / b).to_string(),
+        "3333333333333333333.333333333333333333"
+    );
+    let max = FixedDecimalU128::<18>::MAX;
+    assert_eq!(max / FixedDecimalU128::ONE, max);
+    assert_eq!(
+        max.checked_div(FixedDecimalU128::<18>::from_str("0.1").unwrap()),
+        None
+    );
+    assert_eq!(max.mul_div(max, max, RoundingStrategy::default()), max);
+
+    // Dividends scaled by `10^39` fit in 256 bits whenever the quotient fits.
+    let d = |s| FixedDecimalI128::<39>::from_str(s).unwrap();
+    assert_eq!(d("0.012") / d("0.15"), d("0.08"));
+    assert_eq!(
+        (d("0.001") / d("-0.0123")).to_string(),
+        "-0.081300813008130081300813008130081300813"
+    );
+    assert_eq!(d("0.12").checked_div(d("0.15")), None);
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn it_panics_on_div_by_zero() {
+    let _ = FixedDecimalI128::<2>::ONE / FixedDecimalI128::<2>::new(0);
+}
+
+proptest! {
+    #[test]
+    fn divs_inverts_mults(a in any::<i32>(), b in any::<i32>()) {
+        prop_assume!(b != 0);
+        let a = FixedDecimalI128::<4>::new(a.into());
+        let b = FixedDecimalI128::<4>::new(b as i128 * 10_000);
+        assert_eq!((a * b) / b, a);
+    }
+}
+
+// Rem
+
+#[test]
+fn it_can_rem_simple() {
+    let a = FixedDecimalI128::<2>::from_str("10.50").unwrap();
+    let b = FixedDecimalI128::<2>::from_str("3").unwrap();
+    assert_eq!((a % b).to_string(), "1.50");
+    assert_eq!((-a % b).to_string(), "-1.50");
+    assert_eq!((a % -b).to_string(), "1.50");
+}
+
+#[test]
+fn it_can_div_rem() {
+    let a = FixedDecimalI128::<2>::from_str("-10.50").unwrap();
+    let b = FixedDecimalI128::<2>::from_str("0.25").unwrap();
+    assert_eq!(a.div_rem(b), (-42, FixedDecimalI128::new(0)));
+
+    let b = FixedDecimalI128::<2>::from_str("4").unwrap();
+    assert_eq!(
+        a.div_rem(b),
+        (-2, FixedDecimalI128::from_str("-2.50").unwrap())
+    );
+}
+
+#[test]
+fn it_can_div_rem_euclid() {
+    let a = FixedDecimalI128::<2>::from_str("10.50").unwrap();
+    let b = FixedDecimalI128::<2>::from_str("4").unwrap();
+
+    assert_eq!(a.div_euclid(b), 2);
+    assert_eq!(a.rem_euclid(b).to_string(), "2.50");
+    assert_eq!((-a).div_euclid(b), -3);
+    assert_eq!((-a).rem_euclid(b).to_string(), "1.50");
+    assert_eq!(a.div_euclid(-b), -2);
+    assert_eq!(a.rem_euclid(-b).to_string(), "2.50");
+    assert_eq!((-a).div_euclid(-b), 3);
+    assert_eq!((-a).rem_euclid(-b).to_string(), "1.50");
+}
+
+#[test]
+#[should_panic]
+fn it_panics_on_rem_by_zero() {
+    let _ = FixedDecimalI128::<2>::ONE % FixedDecimalI128::<2>::new(0);
+}
+
+proptest! {
+    #[test]
+    fn div_rem_recomposes(a in any::<i64>(), b in any::<i64>()) {
+        prop_assume!(b != 0);
+        let a = FixedDecimalI128::<5>::new(a.into());
+        let b = FixedDecimalI128::<5>::new(b.into());
+
+        let (q, r) = a.div_rem(b);
+        assert_eq!(b * q + r, a);
+
+        let r = a.rem_euclid(b);
+        assert!(r.mantissa() >= 0);
+        assert_eq!(b * a.div_euclid(b) + r, a);
+    }
+}
+
+// Operator matrix
+
+fn sum_of_squares<D>(values: &[D], zero: D) -> D
+where
+    D: Copy,
+    for<'a> &'a D: std::ops::Add<&'a D, Output = D> + std::ops::Mul<&'a D, Output = D>,
+{
+    values.iter().fold(zero, |acc, v| &acc + &(v * v))
+}
+
+#[test]
+#[expect(clippy::op_ref)]
+fn it_accepts_borrowed_operands() {
+    let a = FixedDecimalI64::<2>::from_str("10.50").unwrap();
+    let b = FixedDecimalI64::<2>::from_str("4.00").unwrap();
+    let results =
+        |a: FixedDecimalI64<2>, b: FixedDecimalI64<2>| [a + b, a - b, a * b, a / b, a % b];
+    let expected = results(a, b);
+    assert_eq!([&a + b, &a - b, &a * b, &a / b, &a % b], expected);
+    assert_eq!([a + &b, a - &b, a * &b, a / &b, a % &b], expected);
+    assert_eq!([&a + &b, &a - &b, &a * &b, &a / &b, &a % &b], expected);
+    assert_eq!(-&a, -a);
+
+    let n = 3i64;
+    let expected = [a + n, a - n, a * n, a / n, a % n];
+    assert_eq!([&a + n, &a - n, &a * n, &a / n, &a % n], expected);
+    assert_eq!([a + &n, a - &n, a * &n, a / &n, a % &n], expected);
+    assert_eq!([&a + &n, &a - &n, &a * &n, &a / &n, &a % &n], expected);
+
+    let values = [a, b];
+    assert_eq!(
+        sum_of_squares(&values, FixedDecimalI64::<2>::new(0)).to_string(),
+        "126.25"
+    );
+}
+
+#[test]
+fn it_can_assign_all_operators() {
+    let b = FixedDecimalI64::<2>::from_str("4.00").unwrap();
+    let mut a = FixedDecimalI64::<2>::from_str("10.50").unwrap();
+    a -= b;
+    assert_eq!(a.to_string(), "6.50");
+    a *= &b;
+    assert_eq!(a.to_string(), "26.00");
+    a /= FixedDecimalI64::<2>::from_str("3.00").unwrap();
+    assert_eq!(a.to_string(), "8.67");
+    a %= &b;
+    assert_eq!(a.to_string(), "0.67");
+
+    let mut c = &mut a;
+    c *= b;
+    c -= &b;
+    c /= &b;
+    c %= FixedDecimalI64::<2>::from_str("0.10").unwrap();
+    assert_eq!(a.to_string(), "-0.03");
+
+    let mut a = FixedDecimalI64::<2>::from_str("10.50").unwrap();
+    a += 1i64;
+    a -= &2;
+    assert_eq!(a.to_string(), "9.50");
+    a *= 2i64;
+    a /= &4;
+    assert_eq!(a.to_string(), "4.75");
+    a %= 3;
+    assert_eq!(a.to_string(), "1.75");
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn it_panics_on_div_assign_by_zero() {
+    let mut a = FixedDecimalI64::<2>::ONE;
+    a /= &FixedDecimalI64::new(0);
+}
+
+// Fused
+
+#[test]
+fn it_can_mul_div() {
+    let d = |s| FixedDecimalI64::<2>::from_str(s).unwrap();
+    let cases = [
+        (
+            "100.00",
+            "2.00",
+            "3.00",
+            RoundingStrategy::default(),
+            "66.67",
+        ),
+        (
+            "100.00",
+            "2.00",
+            "-3.00",
+            RoundingStrategy::default(),
+            "-66.67",
+        ),
+        (
+            "-100.00",
+            "-2.00",
+            "-3.00",
+            RoundingStrategy::ToZero,
+            "-66.66",
+        ),
+        ("0.05", "0.10", "0.02", RoundingStrategy::default(), "0.25"),
+        (
+            "0.01",
+            "0.50",
+            "1.00",
+            RoundingStrategy::MidpointNearestEven,
+            "0.00",
+        ),
+        (
+            "0.01",
+            "0.50",
+            "1.00",
+            RoundingStrategy::MidpointAwayFromZero,
+            "0.01",
+        ),
+        (
+            "-0.01",
+            "0.50",
+            "1.00",
+            RoundingStrategy::ToNegativeInfinity,
+            "-0.01",
+        ),
+        (
+            "0.00",
+            "-0.50",
+            "1.00",
+            RoundingStrategy::ToNegativeInfinity,
+            "0.00",
+        ),
+    ];
+    for (amount, a, b, strategy, expected) in cases {
+        assert_eq!(
+            d(amount).mul_div(d(a), d(b), strategy).to_string(),
+            expected
+        );
+    }
+
+    // The product overflows the scale but not the double width intermediate.
+    let max = FixedDecimalI64::<2>::MAX;
+    assert_eq!(
+        max.mul_div(d("3.00"), d("3.00"), RoundingStrategy::default()),
+        max
+    );
+    assert_eq!(
+        max.checked_mul_div(d("3.00"), d("2.00"), RoundingStrategy::default()),
+        None
+    );
+    assert_eq!(
+        max.checked_mul_div(max, d("0.00"), RoundingStrategy::default()),
+        None
+    );
+}
+
+#[test]
+fn it_can_mul_add() {
+    let d = |s| FixedDecimalI64::<2>::from_str(s).unwrap();
+    let cases = [
+        ("1.50", "2.00", "0.25", "3.25"),
+        ("1.50", "-2.00", "0.25", "-2.75"),
+        ("-1.50", "-2.00", "-3.00", "0.00"),
+        ("0.05", "0.10", "0.01", "0.02"),
+        ("0.05", "0.50", "-0.01", "0.02"),
+        ("0.05", "0.30", "-0.01", "0.00"),
+        ("0.00", "-1.00", "0.01", "0.01"),
+    ];
+    for (x, a, b, expected) in cases {
+        assert_eq!(d(x).mul_add(d(a), d(b)).to_string(), expected);
+        assert_eq!(MulAdd::mul_add(d(x), d(a), d(b)).to_string(), expected);
+    }
+
+    let max = FixedDecimalI64::<2>::MAX;
+    assert_eq!(max.mul_add(d("2.00"), -max), max);
+    assert_eq!(max.checked_mul_add(d("1.00"), d("0.01")), None);
+    assert_eq!(
+        FixedDecimalU128::<2>::ONE
+            .checked_mul_add(FixedDecimalU128::ONE, FixedDecimalU128::ONE)
+            .unwrap()
+            .to_string(),
+        "2.00"
+    );
+
+    // `0.127 * 10^3` needs more than 16 bits, the result does not.
+    let d = FixedDecimalI8::<3>::new;
+    assert_eq!(d(-120).mul_add(d(100), d(127)), d(115));
+    assert_eq!(d(100).mul_add(d(100), d(-127)), d(-117));
+    assert_eq!(d(127).checked_mul_add(d(127), d(127)), None);
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn it_panics_on_mul_div_by_zero() {
+    let one = FixedDecimalI64::<2>::ONE;
+    let _ = one.mul_div(one, FixedDecimalI64::new(0), RoundingStrategy::default());
+}
+
+proptest! {
+    #[test]
+    fn mul_div_rounds_once(x in any::<i32>(), a in any::<i32>(), b in any::<i32>()) {
+        prop_assume!(b != 0);
+        let (x, a, b) = (x as i64, a as i64, b as i64);
+        let result = FixedDecimalI64::<3>::new(x)
+            .checked_mul_div(FixedDecimalI64::new(a), FixedDecimalI64::new(b), RoundingStrategy::ToNegativeInfinity);
+        // Flooring division, `div_euclid` only floors for positive divisors.
+        let (product, b) = (x as i128 * a as i128 * b.signum() as i128, b.abs() as i128);
+        let exact = product.div_euclid(b);
+        assert_eq!(result.map(|r| r.mantissa()), i64::try_from(exact).ok());
+    }
+
+    #[test]
+    fn mul_add_matches_wide_computation(x in any::<i32>(), a in any::<i32>(), b in any::<i32>()) {
+        let (x, a, b) = (x as i64, a as i64, b as i64);
+        let result = FixedDecimalI64::<3>::new(x)
+            .mul_add(FixedDecimalI64::new(a), FixedDecimalI64::new(b));
+        let exact = FixedDecimalI128::<6>::new(x as i128 * a as i128 + b as i128 * 1000);
+        assert_eq!(result.mantissa() as i128, exact.rescale::<3>(RoundingStrategy::default()).mantissa());
+    }
+}
+
+// Checked
+
+fn checked_ops<D>(a: D, b: D) -> [Option<D>; 6]
+where
+    D: CheckedAdd + CheckedSub + CheckedMul + CheckedDiv + CheckedRem + CheckedNeg,
+{
+    [
+        a.checked_add(&b),
+        a.checked_sub(&b),
+        a.checked_mul(&b),
+        a.checked_div(&b),
+        a.checked_rem(&b),
+        a.checked_neg(),
+    ]
+}
+
+#[test]
+fn it_can_checked_ops_generically() {
+    let a = FixedDecimalI128::<2>::from_str("7.50").unwrap();
+    let b = FixedDecimalI128::<2>::from_str("-2").unwrap();
+    let results = checked_ops(a, b).map(|r| r.unwrap().to_string());
+    assert_eq!(
+        results,
+        ["5.50", "9.50", "-15.00", "-3.75", "1.50", "-7.50"]
+    );
+
+    let zero = FixedDecimalI128::<2>::new(0);
+    assert_eq!(
+        checked_ops(a, zero),
+        [Some(a), Some(a), Some(zero), None, None, Some(-a)]
+    );
+}
+
+#[test]
+fn it_checks_ops_overflow() {
+    let min = FixedDecimalI128::<2>::MIN;
+    let max = FixedDecimalI128::<2>::MAX;
+    let one = FixedDecimalI128::<2>::ONE;
+    assert_eq!(min.checked_neg(), None);
+    assert_eq!(max.checked_neg(), Some(min + FixedDecimalI128::new(1)));
+    assert_eq!(min.checked_sub(&one), None);
+    assert_eq!(max.checked_add(&one), None);
+    assert_eq!(min.checked_mul_int(-1), None);
+    assert_eq!(max.checked_mul_int(1), Some(max));
+    assert_eq!(
+        FixedDecimalI128::<0>::MIN.checked_rem(&-FixedDecimalI128::<0>::ONE),
+        None
+    );
+
+    let one = FixedDecimalU128::<2>::ONE;
+    assert_eq!(one.checked_neg(), None);
+    assert_eq!(
+        FixedDecimalU128::<2>::new(0).checked_neg(),
+        Some(FixedDecimalU128::new(0))
+    );
+    assert_eq!(FixedDecimalU128::<2>::new(0).checked_sub(&one), None);
+}
+
+proptest! {
+    #[test]
+    fn checked_subs_works_as_internal(a in any::<i128>(), b in any::<i128>()) {
+        assert_eq!(FixedDecimalI128::<7>::new(a).checked_sub(&FixedDecimalI128::<7>::new(b)), a.checked_sub(b).map(FixedDecimalI128::<7>::new))
+    }
+
+    #[test]
+    fn checked_mults_int_works_as_internal(a in any::<i128>(), b in any::<i128>()) {
+        assert_eq!(FixedDecimalI128::<7>::new(a).checked_mul_int(b), a.checked_mul(b).map(FixedDecimalI128::<7>::new))
+    }
+}
+
+// Saturating
+
+#[test]
+fn it_can_saturate() {
+    let max = FixedDecimalI64::<2>::MAX;
+    let min = FixedDecimalI64::<2>::MIN;
+    let two = FixedDecimalI64::<2>::from_str("2").unwrap();
+    let half = FixedDecimalI64::<2>::from_str("0.5").unwrap();
+
+    assert_eq!(max.saturating_add(two), max);
+    assert_eq!(min.saturating_add(-two), min);
+    assert_eq!(min.saturating_sub(two), min);
+    assert_eq!(max.saturating_sub(-two), max);
+    assert_eq!(max.saturating_mul(two), max);
+    assert_eq!(max.saturating_mul(-two), min);
+    assert_eq!(min.saturating_mul(-two), max);
+    assert_eq!(max.saturating_div(half), max);
+    assert_eq!(min.saturating_div(-half), max);
+    assert_eq!(min.saturating_div(half), min);
+
+    assert_eq!(two.saturating_add(half).to_string(), "2.50");
+    assert_eq!(two.saturating_sub(half).to_string(), "1.50");
+    assert_eq!(two.saturating_mul(half).to_string(), "1.00");
+    assert_eq!(two.saturating_div(half).to_string(), "4.00");
+
+    let zero = FixedDecimalU128::<2>::new(0);
+    assert_eq!(zero.saturating_sub(FixedDecimalU128::<2>::ONE), zero);
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn it_panics_on_saturating_div_by_zero() {
+    let _ = FixedDecimalI64::<2>::ONE.saturating_div(FixedDecimalI64::new(0));
+}
+
+proptest! {
+    #[test]
+    fn saturating_ops_works_as_generic(a in any::<i64>(), b in any::<i64>()) {
+        let a = FixedDecimalI64::<4>::new(a);
+        let b = FixedDecimalI64::<4>::new(b);
+        assert_eq!(SaturatingAdd::saturating_add(&a, &b), a.saturating_add(b));
+        assert_eq!(SaturatingSub::saturating_sub(&a, &b), a.saturating_sub(b));
+        assert_eq!(SaturatingMul::saturating_mul(&a, &b), a.saturating_mul(b));
+        assert_eq!(a.saturating_add(b).mantissa(), a.mantissa().saturating_add(b.mantissa()));
+        assert_eq!(a.saturating_sub(b).mantissa(), a.mantissa().saturating_sub(b.mantissa()));
+    }
+}
+
+// Wrapping
+
+#[test]
+fn it_can_wrap() {
+    let max = FixedDecimalI64::<2>::MAX;
+    let min = FixedDecimalI64::<2>::MIN;
+    let unit = FixedDecimalI64::<2>::new(1);
+
+    assert_eq!(max.wrapping_add(unit), min);
+    assert_eq!(min.wrapping_sub(unit), max);
+    assert_eq!(min.wrapping_neg(), min);
+    assert_eq!(max.wrapping_mul_int(2), FixedDecimalI64::<2>::new(-2));
+    assert_eq!(max.overflowing_add(unit), (min, true));
+    assert_eq!(min.overflowing_sub(unit), (max, true));
+    assert_eq!(min.overflowing_neg(), (min, true));
+    assert_eq!(max.overflowing_neg(), (min + unit, false));
+    assert_eq!(max.overflowing_mul_int(-1), (min + unit, false));
+
+    let two = FixedDecimalI64::<2>::from_str("2").unwrap();
+    assert_eq!(
+        max.overflowing_mul(two),
+        (FixedDecimalI64::<2>::new(-2), true)
+    );
+    assert_eq!(two.overflowing_mul(two), (two + two, false));
+    assert_eq!(max.wrapping_mul(-two), FixedDecimalI64::<2>::new(2));
+}
+
+proptest! {
+    #[test]
+    fn wrapping_ops_works_as_internal(a in any::<i32>(), b in any::<i32>()) {
+        let (da, db) = (FixedDecimalI32::<0>::new(a), FixedDecimalI32::<0>::new(b));
+        assert_eq!(da.wrapping_add(db).mantissa(), a.wrapping_add(b));
+        assert_eq!(da.wrapping_sub(db).mantissa(), a.wrapping_sub(b));
+        assert_eq!(da.wrapping_mul(db).mantissa(), a.wrapping_mul(b));
+        assert_eq!(da.wrapping_neg().mantissa(), a.wrapping_neg());
+        assert_eq!(da.overflowing_mul(db), (FixedDecimalI32::new(a.wrapping_mul(b)), a.checked_mul(b).is_none()));
+        assert_eq!(WrappingAdd::wrapping_add(&da, &db), da.wrapping_add(db));
+        assert_eq!(WrappingSub::wrapping_sub(&da, &db), da.wrapping_sub(db));
+        assert_eq!(WrappingMul::wrapping_mul(&da, &db), da.wrapping_mul(db));
+        assert_eq!(WrappingNeg::wrapping_neg(&da), da.wrapping_neg());
+    }
+
+    #[test]
+    fn overflowing_mul_agrees_with_checked(a in any::<i64>(), b in any::<i64>()) {
+        let (a, b) = (FixedDecimalI64::<6>::new(a), FixedDecimalI64::<6>::new(b));
+        let (value, overflow) = a.overflowing_mul(b);
+        assert_eq!(a.checked_mul(b), (!overflow).then_some(value));
+    }
+}
+
+// Rounding
+
+#[test]
+fn it_can_round_dp() {
+    let strategies = [
+        RoundingStrategy::MidpointNearestEven,
+        RoundingStrategy::MidpointAwayFromZero,
+        RoundingStrategy::MidpointTowardZero,
+        RoundingStrategy::ToZero,
+        RoundingStrategy::AwayFromZero,
+        RoundingStrategy::ToNegativeInfinity,
+        RoundingStrategy::ToPositiveInfinity,
+    ];
+    let cases = [
+        ("5.5", ["6", "6", "5", "5", "6", "5", "6"]),
+        ("2.5", ["2", "3", "2", "2", "3", "2", "3"]),
+        ("1.6", ["2", "2", "2", "1", "2", "1", "2"]),
+        ("1.1", ["1", "1", "1", "1", "2", "1", "2"]),
+        ("1.0", ["1", "1", "1", "1", "1", "1", "1"]),
+        ("-1.0", ["-1", "-1", "-1", "-1", "-1", "-1", "-1"]),
+        ("-1.1", ["-1", "-1", "-1", "-1", "-2", "-2", "-1"]),
+        ("-1.6", ["-2", "-2", "-2", "-1", "-2", "-2", "-1"]),
+        ("-2.5", ["-2", "-3", "-2", "-2", "-3", "-3", "-2"]),
+        ("-5.5", ["-6", "-6", "-5", "-5", "-6", "-6", "-5"]),
+    ];
+    for (value, expected) in cases {
+        let a = FixedDecimalI64::<1>::from_str(value).unwrap();
+        for (strategy, expected) in strategies.into_iter().zip(expected) {
+            assert_eq!(
+                a.round_dp(0, strategy),
+                FixedDecimalI64::<1>::from_str(expected).unwrap(),
+                "{value} {strategy:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn it_can_round_dp_unsigned() {
+    let a = FixedDecimalU128::<4>::from_str("2.4550").unwrap();
+    assert_eq!(
+        a.round_dp(2, RoundingStrategy::MidpointNearestEven)
+            .to_string(),
+        "2.4600"
+    );
+    assert_eq!(
+        a.round_dp(2, RoundingStrategy::MidpointTowardZero)
+            .to_string(),
+        "2.4500"
+    );
+    assert_eq!(
+        a.round_dp(1, RoundingStrategy::ToNegativeInfinity)
+            .to_string(),
+        "2.4000"
+    );
+    assert_eq!(a.round_dp(3, RoundingStrategy::ToPositiveInfinity), a);
+    assert_eq!(a.round().to_string(), "2.0000");
+    assert_eq!(a.fract().to_string(), "0.4550");
+}
+
+#[test]
+fn it_can_round_shortcuts() {
+    let a = FixedDecimalI128::<3>::from_str("-7.505").unwrap();
+    assert_eq!(a.trunc().to_string(), "-7.000");
+    assert_eq!(a.fract().to_string(), "-0.505");
+    assert_eq!(a.floor().to_string(), "-8.000");
+    assert_eq!(a.ceil().to_string(), "-7.000");
+    assert_eq!(a.round().to_string(), "-8.000");
+
+    let a = FixedDecimalI128::<0>::from_str("-7").unwrap();
+    assert_eq!(a.floor(), a);
+    assert_eq!(a.fract(), FixedDecimalI128::<0>::new(0));
+}
+
+#[test]
+fn it_checks_round_overflow() {
+    let max = FixedDecimalI8::<1>::MAX;
+    assert_eq!(
+        max.checked_round_dp(0, RoundingStrategy::ToPositiveInfinity),
+        None
+    );
+    assert_eq!(max.trunc(), FixedDecimalI8::<1>::new(120));
+    assert_eq!(
+        FixedDecimalI8::<1>::MIN.checked_round_dp(0, RoundingStrategy::ToNegativeInfinity),
+        None
+    );
+    assert_eq!(
+        FixedDecimalI8::<1>::MIN.fract(),
+        FixedDecimalI8::<1>::new(-8)
+    );
+}
+
+proptest! {
+    #[test]
+    fn rounds_keep_bounds(v in any::<i64>()) {
+        let a = FixedDecimalI128::<4>::new(v.into());
+        assert_eq!(a.trunc() + a.fract(), a);
+        assert!(a.floor() <= a && a <= a.ceil());
+        assert!((a - a.round()).mantissa().abs() <= 5_000);
+        assert_eq!(a.round_dp(2, RoundingStrategy::ToZero).mantissa() % 100, 0);
+    }
+}
+
+// Rescale
+
+#[test]
+fn it_can_rescale() {
+    let a = FixedDecimalI128::<2>::from_str("-1.25").unwrap();
+    assert_eq!(a.rescale::<2>(RoundingStrategy::default()), a);
+    assert_eq!(
+        a.rescale::<5>(RoundingStrategy::default()).to_string(),
+        "-1.25000"
+    );
+    assert_eq!(
+        a.rescale::<1>(RoundingStrategy::MidpointNearestEven)
+            .to_string(),
+        "-1.2"
+    );
+    assert_eq!(
+        a.rescale::<1>(RoundingStrategy::MidpointAwayFromZero)
+            .to_string(),
+        "-1.3"
+    );
+    assert_eq!(
+        a.rescale::<0>(RoundingStrategy::ToPositiveInfinity)
+            .to_string(),
+        "-1"
+    );
+    assert_eq!(
+        a.rescale::<0>(RoundingStrategy::ToNegativeInfinity)
+            .to_string(),
+        "-2"
+    );
+    assert_eq!(a.with_scale::<4>().to_string(), "-0.0125");
+}
+
+#[test]
+fn it_checks_rescale_overflow() {
+    let a = FixedDecimalI128::<0>::MAX;
+    assert_eq!(a.checked_rescale::<1>(RoundingStrategy::default()), None);
+    assert_eq!(a.exact_rescale::<1>(), Err(ArithmeticError::PosOverflow));
+    assert_eq!(
+        FixedDecimalI128::<0>::MIN.exact_rescale::<1>(),
+        Err(ArithmeticError::NegOverflow)
+    );
+
+    let tiny = FixedDecimalI128::<{ u8::MAX }>::new(1);
+    assert_eq!(
+        tiny.checked_rescale::<0>(RoundingStrategy::AwayFromZero),
+        Some(FixedDecimalI128::new(1))
+    );
+    assert_eq!(tiny.exact_rescale::<0>(), Err(ArithmeticError::Underflow));
+    assert_eq!(
+        FixedDecimalI128::<0>::new(0).exact_rescale::<{ u8::MAX }>(),
+        Ok(FixedDecimalI128::new(0))
+    );
+}
+
+proptest! {
+    #[test]
+    fn rescales_roundtrip(v in any::<i64>()) {
+        let a = FixedDecimalI128::<3>::new(v.into());
+        let wider = a.exact_rescale::<9>().unwrap();
+        assert_eq!(wider.exact_rescale::<3>(), Ok(a));
+        assert_eq!(wider.rescale::<3>(RoundingStrategy::ToZero), a);
+        assert_eq!(
+            a.rescale::<1>(RoundingStrategy::ToZero).rescale::<3>(RoundingStrategy::ToZero),
+            a.round_dp(1, RoundingStrategy::ToZero)
+        );
+    }
+}
+
+// Residuals
+
+#[test]
+fn it_rounds_with_residual() {
+    let d = |s| FixedDecimalI64::<4>::from_str(s).unwrap();
+    let cases = [
+        (
+            "1.2350",
+            2,
+            RoundingStrategy::MidpointNearestEven,
+            "1.2400",
+            "-0.0050",
+        ),
+        ("1.2350", 2, RoundingStrategy::ToZero, "1.2300", "0.0050"),
+        (
+            "-1.2351",
+            2,
+            RoundingStrategy::MidpointTowardZero,
+            "-1.2400",
+            "0.0049",
+        ),
+        (
+            "-0.0001",
+            0,
+            RoundingStrategy::ToNegativeInfinity,
+            "-1.0000",
+            "0.9999",
+        ),
+        (
+            "-0.0001",
+            0,
+            RoundingStrategy::ToPositiveInfinity,
+            "0.0000",
+            "-0.0001",
+        ),
+        (
+            "7.0000",
+            2,
+            RoundingStrategy::AwayFromZero,
+            "7.0000",
+            "0.0000",
+        ),
+        (
+            "1.2345",
+            6,
+            RoundingStrategy::AwayFromZero,
+            "1.2345",
+            "0.0000",
+        ),
+    ];
+    for (value, places, strategy, rounded, residual) in cases {
+        let (r, rest) = d(value).round_with_residual(places, strategy);
+        assert_eq!(
+            (r.to_string(), rest.to_string()),
+            (rounded.into(), residual.into())
+        );
+    }
+
+    let min = FixedDecimalI8::<1>::MIN;
+    assert_eq!(
+        min.checked_round_with_residual(0, RoundingStrategy::ToZero),
+        Some((FixedDecimalI8::new(-120), FixedDecimalI8::new(-8)))
+    );
+    assert_eq!(
+        min.checked_round_with_residual(0, RoundingStrategy::AwayFromZero),
+        None
+    );
+}
+
+#[test]
+fn it_rescales_with_residual() {
+    let a = FixedDecimalI64::<4>::from_str("2.3456").unwrap();
+    let (rounded, residual) = a.rescale_with_residual::<1>(RoundingStrategy::MidpointAwayFromZero);
+    assert_eq!(
+        (rounded.to_string(), residual.to_string()),
+        ("2.3".into(), "0.0456".into())
+    );
+    let (rounded, residual) = (-a).rescale_with_residual::<2>(RoundingStrategy::ToNegativeInfinity);
+    assert_eq!(
+        (rounded.to_string(), residual.to_string()),
+        ("-2.35".into(), "0.0044".into())
+    );
+    let (rounded, residual) = a.rescale_with_residual::<6>(RoundingStrategy::ToZero);
+    assert_eq!(
+        (rounded.to_string(), residual.to_string()),
+        ("2.345600".into(), "0.0000".into())
+    );
+
+    // The residual needs more digits than the backing type has.
+    let tiny = FixedDecimalI8::<3>::new(-1);
+    assert_eq!(
+        tiny.checked_rescale_with_residual::<0>(RoundingStrategy::AwayFromZero),
+        None
+    );
+    assert_eq!(
+        tiny.checked_rescale_with_residual::<0>(RoundingStrategy::ToZero),
+        Some((FixedDecimalI8::new(0), tiny))
+    );
+}
+
+#[test]
+fn it_divs_with_residual() {
+    let d = |s| FixedDecimalI64::<2>::from_str(s).unwrap();
+    let cases = [
+        (
+            "10.00",
+            "3.00",
+            RoundingStrategy::default(),
+            "3.33",
+            "0.0100",
+        ),
+        (
+            "20.00",
+            "3.00",
+            RoundingStrategy::default(),
+            "6.67",
+            "-0.0100",
+        ),
+        (
+            "-20.00",
+            "3.00",
+            RoundingStrategy::ToZero,
+            "-6.66",
+            "-0.0200",
+        ),
+        (
+            "-20.00",
+            "-3.00",
+            RoundingStrategy::ToNegativeInfinity,
+            "6.66",
+            "-0.0200",
+        ),
+        (
+            "1.00",
+            "0.07",
+            RoundingStrategy::ToPositiveInfinity,
+            "14.29",
+            "-0.0003",
+        ),
+        (
+            "0.00",
+            "0.07",
+            RoundingStrategy::AwayFromZero,
+            "0.00",
+            "0.0000",
+        ),
+    ];
+    for (a, b, strategy, rounded, residual) in cases {
+        let (r, rest): (_, FixedDecimalI64<4>) = d(a).div_rem_rounded(d(b), strategy);
+        assert_eq!(
+            (r.to_string(), rest.to_string()),
+            (rounded.into(), residual.into())
+        );
+    }
+
+    let (r, rest): (_, FixedDecimalI64<6>) =
+        d("10.00").div_rem_rounded(d("3.00"), RoundingStrategy::default());
+    assert_eq!(
+        (r.to_string(), rest.to_string()),
+        ("3.33".into(), "0.010000".into())
+    );
+    assert_eq!(
+        FixedDecimalI64::<2>::MAX
+            .checked_div_rem_rounded::<4>(d("0.50"), RoundingStrategy::default()),
+        None
+    );
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn it_panics_on_div_with_residual_by_zero() {
+    let one = FixedDecimalI64::<2>::ONE;
+    let _: (_, FixedDecimalI64<4>) =
+        one.div_rem_rounded(FixedDecimalI64::new(0), RoundingStrategy::default());
+}
+
+proptest! {
+    #[test]
+    fn residuals_recompose(a in any::<i64>(), b in any::<i64>(), places in 0u8..6) {
+        let strategy = RoundingStrategy::MidpointAwayFromZero;
+        let a = FixedDecimalI128::<4>::new(a.into());
+        let (rounded, residual) = a.round_with_residual(places, strategy);
+        assert_eq!(rounded, a.round_dp(places, strategy));
+        assert_eq!(rounded + residual, a);
+
+        let (rounded, residual) = a.rescale_with_residual::<1>(strategy);
+        assert_eq!(rounded, a.rescale::<1>(strategy));
+        assert_eq!(rounded.rescale::<4>(strategy) + residual, a);
+
+        prop_assume!(b != 0);
+        let b = FixedDecimalI128::<4>::new(b.into());
+        let (rounded, residual): (_, FixedDecimalI128<8>) = a.div_rem_rounded(b, strategy);
+        assert_eq!(rounded, a.div_with_rounding(b, strategy));
+        assert_eq!(rounded.mul_into::<4, 8>(b, strategy).unwrap() + residual, a);
+    }
+}
+
+// Conversions
+
+#[test]
+fn it_converts_between_backings() {
+    let balance = FixedDecimalI64::<8>::from_str("-12.34567891").unwrap();
+    let wide: FixedDecimalI128<8> = balance.into();
+    assert_eq!(wide.to_string(), "-12.34567891");
+    assert_eq!(FixedDecimalI64::<8>::try_from(wide), Ok(balance));
+
+    let unsigned = FixedDecimal::<u32, 2>::new(u32::MAX);
+    let signed: FixedDecimalI64<2> = unsigned.into();
+    assert_eq!(signed.mantissa(), u32::MAX as i64);
+
+    assert!(FixedDecimal::<u64, 8>::try_from(balance).is_err());
+    assert!(FixedDecimal::<i32, 8>::try_from(FixedDecimalI64::<8>::MAX).is_err());
+    assert!(FixedDecimal::<i8, 2>::try_from(FixedDecimalU128::<2>::new(127)).is_ok());
+    assert!(FixedDecimal::<i8, 2>::try_from(FixedDecimalU128::<2>::new(128)).is_err());
+}
+
+#[test]
+fn it_takes_unsigned_abs() {
+    let a = FixedDecimalI128::<2>::from_str("-1.50").unwrap();
+    assert_eq!(a.unsigned_abs().to_string(), "1.50");
+    assert_eq!((-a).unsigned_abs().to_string(), "1.50");
+    assert_eq!(
+        FixedDecimalI128::<2>::MIN.unsigned_abs().mantissa(),
+        i128::MIN.unsigned_abs()
+    );
+}
+
+proptest! {
+    #[test]
+    fn conversions_works_as_internal(v in any::<i64>()) {
+        let a = FixedDecimalI64::<4>::new(v);
+        let wide: FixedDecimalI128<4> = a.into();
+        assert_eq!(wide.mantissa(), v as i128);
+        assert_eq!(FixedDecimal::<i32, 4>::try_from(a).ok().map(|d| d.mantissa()), i32::try_from(v).ok());
+        assert_eq!(FixedDecimal::<u64, 4>::try_from(a).ok().map(|d| d.mantissa()), u64::try_from(v).ok());
+        assert_eq!(a.unsigned_abs().mantissa(), v.unsigned_abs());
+    }
+}
+
+// Floats
+
+#[test]
+fn it_converts_from_floats() {
+    let a = FixedDecimalI64::<2>::try_from(1.005f64).unwrap();
+    assert_eq!(a.to_string(), "1.00");
+    let a = FixedDecimalI64::<3>::try_from(-1.0005f64).unwrap();
+    assert_eq!(a.to_string(), "-1.000");
+    let a = FixedDecimalI64::<3>::try_from(-1.0625f64).unwrap();
+    assert_eq!(a.to_string(), "-1.062");
+    let a = FixedDecimalI64::<4>::try_from(0.1f32).unwrap();
+    assert_eq!(a.to_string(), "0.1000");
+    let a = FixedDecimalI128::<20>::from_f64_with_rounding(0.1, RoundingStrategy::ToZero);
+    assert_eq!(a.unwrap().to_string(), "0.10000000000000000555");
+    let a = FixedDecimalI128::<0>::try_from(1e30f64).unwrap();
+    assert_eq!(a.to_string(), "1000000000000000019884624838656");
+    let a = FixedDecimalU128::<2>::try_from(-0.001f64).unwrap();
+    assert_eq!(a, FixedDecimalU128::<2>::new(0));
+}
+
+#[test]
+fn it_converts_tiny_floats() {
+    let tiny = f64::from_bits(1);
+    assert_eq!(
+        FixedDecimalI128::<{ u8::MAX }>::try_from(tiny),
+        Ok(FixedDecimalI128::new(0))
+    );
+    assert_eq!(
+        FixedDecimalI128::<{ u8::MAX }>::from_f64_with_rounding(
+            -tiny,
+            RoundingStrategy::AwayFromZero
+        ),
+        Ok(FixedDecimalI128::new(-1))
+    );
+    assert_eq!(
+        FixedDecimalI128::<{ u8::MAX }>::from_f64_exact(tiny),
+        Err(ArithmeticError::Underflow)
+    );
+}
+
+#[test]
+fn it_rejects_invalid_floats() {
+    assert_eq!(
+        FixedDecimalI64::<2>::try_from(f64::NAN),
+        Err(ArithmeticError::NaN)
+    );
+    assert_eq!(
+        FixedDecimalI64::<2>::try_from(f64::INFINITY),
+        Err(ArithmeticError::PosOverflow)
+    );
+    assert_eq!(
+        FixedDecimalI64::<2>::try_from(f32::NEG_INFINITY),
+        Err(ArithmeticError::NegOverflow)
+    );
+    assert_eq!(
+        FixedDecimalI64::<2>::try_from(1e17f64),
+        Err(ArithmeticError::PosOverflow)
+    );
+    assert_eq!(
+        FixedDecimalU128::<2>::try_from(-1f64),
+        Err(ArithmeticError::NegOverflow)
+    );
+    assert_eq!(
+        FixedDecimalI8::<2>::try_from(-1.29f64),
+        Err(ArithmeticError::NegOverflow)
+    );
+    assert_eq!(
+        FixedDecimalI64::<2>::from_f64_exact(0.125),
+        Err(ArithmeticError::Underflow)
+    );
+}
+
+#[test]
+fn it_converts_to_floats() {
+    let a = FixedDecimalI128::<20>::from_str("0.1").unwrap();
+    assert_eq!(a.to_f64(), 0.1);
+    assert_eq!((-a).to_f32(), -0.1);
+    assert_eq!(FixedDecimalI128::<0>::MAX.to_f64(), i128::MAX as f64);
+    assert_eq!(FixedDecimalU128::<{ u8::MAX }>::new(1).to_f64(), 1e-255);
+    assert_eq!(FixedDecimalI128::<2>::new(0).to_f64(), 0.0);
+    let a = FixedDecimalI64::<8>::from_str("9007199254.74099300").unwrap();
+    assert_eq!(a.to_f64(), 9007199254.740993);
+}
+
+proptest! {
+    #[test]
+    fn floats_roundtrip(v in any::<i64>()) {
+        let a = FixedDecimalI64::<6>::new(v);
+        let f = a.to_f64();
+        assert_eq!(f, a.to_string().parse::<f64>().unwrap());
+        if v.unsigned_abs() < 1 << 52 {
+            assert_eq!(FixedDecimalI64::<6>::try_from(f), Ok(a));
+        }
+    }
+
+    #[test]
+    fn floats_round_as_strategy(f in -1e6f64..1e6) {
+        let floor = FixedDecimalI64::<3>::from_f64_with_rounding(f, RoundingStrategy::ToNegativeInfinity).unwrap();
+        let ceil = FixedDecimalI64::<3>::from_f64_with_rounding(f, RoundingStrategy::ToPositiveInfinity).unwrap();
+        assert!(floor.to_f64() <= f && f <= ceil.to_f64());
+        assert!((ceil - floor).mantissa() <= 1);
+    }
+}
+
+// Integers
+
+#[test]
+fn it_builds_from_integers() {
+    assert_eq!(FixedDecimalI64::<2>::from_int(5).to_string(), "5.00");
+    assert_eq!(FixedDecimalI64::<2>::from_int(-5).to_string(), "-5.00");
+    assert_eq!(
+        FixedDecimalI64::<2>::try_from(7u8).unwrap().to_string(),
+        "7.00"
+    );
+    assert_eq!(
+        FixedDecimalU128::<2>::try_from(7i32).unwrap().to_string(),
+        "7.00"
+    );
+    assert_eq!(
+        FixedDecimalI8::<1>::try_from(12u64),
+        Ok(FixedDecimalI8::new(120))
+    );
+    assert_eq!(
+        FixedDecimalI8::<1>::try_from(13u64),
+        Err(ArithmeticError::PosOverflow)
+    );
+    assert_eq!(
+        FixedDecimalI8::<1>::try_from(-13i64),
+        Err(ArithmeticError::NegOverflow)
+    );
+    assert_eq!(
+        FixedDecimalU128::<0>::try_from(-1i8),
+        Err(ArithmeticError::NegOverflow)
+    );
+    assert_eq!(
+        FixedDecimalI64::<2>::try_from(i64::MAX),
+        Err(ArithmeticError::PosOverflow)
+    );
+    assert_eq!(
+        FixedDecimalI64::<0>::checked_from_int(i64::MIN),
+        Some(FixedDecimalI64::MIN)
+    );
+}
+
+#[test]
+#[should_panic]
+fn it_panics_on_from_int_overflow() {
+    let _ = FixedDecimalI8::<2>::from_int(2);
+}
+
+#[test]
+fn it_splits_into_parts() {
+    let a = FixedDecimalI128::<3>::from_str("-12.034").unwrap();
+    assert_eq!(a.into_parts(), (-12, -34));
+    assert_eq!(FixedDecimalI128::<3>::from_parts(-12, -34), Some(a));
+    assert_eq!(FixedDecimalI128::<3>::from_parts(-12, 34), None);
+    assert_eq!(
+        FixedDecimalI128::<3>::from_parts(0, -34)
+            .unwrap()
+            .to_string(),
+        "-0.034"
+    );
+    assert_eq!(
+        FixedDecimalI128::<3>::from_parts(-12, 0)
+            .unwrap()
+            .to_string(),
+        "-12.000"
+    );
+    assert_eq!(FixedDecimalI128::<3>::from_parts(1, 1000), None);
+    assert_eq!(FixedDecimalI8::<0>::MIN.into_parts(), (-128, 0));
+    assert_eq!(FixedDecimalI8::<2>::MIN.into_parts(), (-1, -28));
+    assert_eq!(FixedDecimalI8::<3>::MIN.into_parts(), (0, -128));
+    assert_eq!(FixedDecimalI8::<2>::from_parts(-1, -29), None);
+    assert_eq!(FixedDecimalI8::<{ u8::MAX }>::MIN.into_parts(), (0, -128));
+}
+
+#[test]
+fn it_rounds_to_integer() {
+    let a = FixedDecimalI64::<1>::from_str("2.5").unwrap();
+    assert_eq!(a.to_integer(RoundingStrategy::MidpointNearestEven), 2);
+    assert_eq!(a.to_integer(RoundingStrategy::MidpointAwayFromZero), 3);
+    assert_eq!((-a).to_integer(RoundingStrategy::ToNegativeInfinity), -3);
+    assert_eq!((-a).to_integer(RoundingStrategy::ToPositiveInfinity), -2);
+    assert_eq!(
+        FixedDecimalI8::<1>::MIN.to_integer(RoundingStrategy::AwayFromZero),
+        -13
+    );
+    assert_eq!(
+        FixedDecimalU128::<0>::MAX.to_integer(RoundingStrategy::AwayFromZero),
+        u128::MAX
+    );
+}
+
+proptest! {
+    #[test]
+    fn parts_roundtrip(v in any::<i128>()) {
+        let a = FixedDecimalI128::<9>::new(v);
+        let (integer, fraction) = a.into_parts();
+        assert_eq!(integer, v / 1_000_000_000);
+        assert_eq!(FixedDecimalI128::<9>::from_parts(integer, fraction), Some(a));
+        assert_eq!(a.to_integer(RoundingStrategy::ToZero), integer);
+    }
+}
+
+#[test]
+fn it_does_arithmetic_with_integers() {
+    let a = FixedDecimalI64::<2>::from_str("10.50").unwrap();
+    assert_eq!((a + 2).to_string(), "12.50");
+    assert_eq!((a - 12).to_string(), "-1.50");
+    assert_eq!((a * 3).to_string(), "31.50");
+    assert_eq!((a / 4).to_string(), "2.62");
+    assert_eq!((a / -4).to_string(), "-2.62");
+    assert_eq!((a % 4).to_string(), "2.50");
+    assert_eq!((-a % 4).to_string(), "-2.50");
+
+    assert_eq!((2 + a).to_string(), "12.50");
+    assert_eq!((12 - a).to_string(), "1.50");
+    assert_eq!((3 * a).to_string(), "31.50");
+    assert_eq!((21 / a).to_string(), "2.00");
+    assert_eq!((-22 % a).to_string(), "-1.00");
+
+    let b = FixedDecimalU8::<1>::new(15);
+    assert_eq!((2u8 - b).to_string(), "0.5");
+    assert_eq!((3u8 / b).to_string(), "2.0");
+    assert_eq!((b % 2u8).to_string(), "1.5");
+    let c = FixedDecimalU128::<18>::from_str("0.5").unwrap();
+    assert_eq!((1u128 - c).to_string(), "0.500000000000000000");
+    assert_eq!((c / 100u128).to_string(), "0.005000000000000000");
+
+    // Dividing does not need the divisor to fit at the scale.
+    let d = FixedDecimalI64::<18>::from_str("9").unwrap();
+    assert_eq!((d / 100).to_string(), "0.090000000000000000");
+    assert_eq!((d % 100).to_string(), "9.000000000000000000");
+}
+
+#[test]
+fn it_checks_arithmetic_with_integers() {
+    let a = FixedDecimalI8::<1>::new(-15);
+    assert_eq!(a.checked_add_int(2), Some(FixedDecimalI8::new(5)));
+    assert_eq!(a.checked_add_int(13), None);
+    assert_eq!(a.checked_sub_int(12), None);
+    assert_eq!(a.checked_div_int(2), Some(FixedDecimalI8::new(-8)));
+    assert_eq!(a.checked_div_int(0), None);
+    assert_eq!(FixedDecimalI8::<1>::MIN.checked_div_int(-1), None);
+    assert_eq!(
+        FixedDecimalI8::<1>::MIN.checked_rem_int(-1),
+        Some(FixedDecimalI8::new(-8))
+    );
+    assert_eq!(a.checked_rem_int(0), None);
+    assert_eq!(a.checked_rem_int(i8::MAX), Some(a));
+
+    assert_eq!(
+        FixedDecimalI8::checked_int_sub(-1, a),
+        Some(FixedDecimalI8::new(5))
+    );
+    assert_eq!(FixedDecimalI8::checked_int_sub(12, a), None);
+    assert_eq!(
+        FixedDecimalI8::checked_int_div(3, a),
+        Some(FixedDecimalI8::new(-20))
+    );
+    assert_eq!(
+        FixedDecimalI8::checked_int_div(3, FixedDecimalI8::<1>::new(0)),
+        None
+    );
+    // 13 does not fit at scale 1, the quotient does.
+    assert_eq!(
+        FixedDecimalI8::checked_int_div(13, a),
+        Some(FixedDecimalI8::new(-87))
+    );
+    assert_eq!(
+        FixedDecimalI8::checked_int_div(13, FixedDecimalI8::<1>::new(5)),
+        None
+    );
+    assert_eq!(
+        FixedDecimalI8::checked_int_rem(4, a),
+        Some(FixedDecimalI8::new(10))
+    );
+    assert_eq!(
+        FixedDecimalI8::checked_int_rem(4, FixedDecimalI8::<1>::new(0)),
+        None
+    );
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn it_panics_on_integer_div_by_zero() {
+    let _ = 1 / FixedDecimalI64::<2>::new(0);
+}
+
+#[test]
+#[cfg_attr(
+    debug_assertions,
+    should_panic(expected = "attempt to subtract with overflow")
+)]
+fn it_overflows_integer_sub_as_the_backing_integer() {
+    let a = FixedDecimalU64::<2>::from_str("1.01").unwrap();
+    assert_eq!(
+        1u64 - a,
+        FixedDecimalU64::<2>::new(100u64.wrapping_sub(101))
+    );
+}
+
+#[test]
+#[cfg_attr(
+    debug_assertions,
+    should_panic(expected = "attempt to multiply with overflow")
+)]
+fn it_overflows_integer_mul_as_the_backing_integer() {
+    let a = FixedDecimalI8::<1>::new(64);
+    assert_eq!(2i8 * a, a * 2i8);
+    assert_eq!(2i8 * a, FixedDecimalI8::<1>::new(-128));
+}
+
+#[test]
+#[cfg_attr(
+    debug_assertions,
+    should_panic(expected = "attempt to multiply with overflow")
+)]
+fn it_overflows_integer_scaling_as_the_backing_integer() {
+    let a = FixedDecimalI8::<1>::new(64);
+    // `100 * 10` wraps to -24.
+    assert_eq!(100i8 + a, a + 100i8);
+    assert_eq!(a + 100i8, FixedDecimalI8::<1>::new(40));
+    assert_eq!(a - (-100i8), a + 100i8);
+    assert_eq!(100i8 - a, FixedDecimalI8::<1>::new(-88));
+}
+
+proptest! {
+    #[test]
+    fn integers_behave_as_decimals(a in any::<i32>(), n in any::<i32>()) {
+        let a = FixedDecimalI64::<3>::new(a.into());
+        let n = n as i64;
+        let d = FixedDecimalI64::<3>::from_int(n);
+        assert_eq!(a + n, a + d);
+        assert_eq!(n + a, a + d);
+        assert_eq!(a - n, a - d);
+        assert_eq!(n - a, d - a);
+        assert_eq!(n * a, a * n);
+        prop_assume!(n != 0);
+        assert_eq!(a / n, a / d);
+        assert_eq!(a % n, a % d);
+        prop_assume!(a.mantissa() != 0);
+        assert_eq!(n / a, d / a);
+        assert_eq!(n % a, d % a);
+    }
+}
+
+// Iterators
+
+#[test]
+fn it_sums_and_multiplies_iterators() {
+    let entries = ["1.25", "-0.50", "3.10"]
+        .iter()
+        .map(|s| FixedDecimalI64::<2>::from_str(s).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        entries.iter().sum::<FixedDecimalI64<2>>().to_string(),
+        "3.85"
+    );
+    assert_eq!(
+        entries
+            .clone()
+            .into_iter()
+            .sum::<FixedDecimalI64<2>>()
+            .to_string(),
+        "3.85"
+    );
+    assert_eq!(
+        entries.iter().product::<FixedDecimalI64<2>>().to_string(),
+        "-1.92"
+    );
+    assert_eq!(
+        Vec::<FixedDecimalI64<2>>::new()
+            .into_iter()
+            .product::<FixedDecimalI64<2>>(),
+        FixedDecimalI64::<2>::ONE
+    );
+    assert_eq!(
+        FixedDecimalI64::checked_sum(&entries).unwrap().to_string(),
+        "3.85"
+    );
+}
+
+#[test]
+fn it_detects_sum_overflow() {
+    let entries = [
+        FixedDecimalI8::<1>::new(100),
+        FixedDecimalI8::<1>::new(100),
+        FixedDecimalI8::<1>::new(-100),
+    ];
+    assert_eq!(FixedDecimalI8::checked_sum(entries), None);
+    assert_eq!(
+        FixedDecimalI8::try_sum(entries.iter()),
+        Err(ArithmeticError::PosOverflow)
+    );
+    assert_eq!(
+        FixedDecimalI8::try_sum(entries.iter().map(|v| -*v)),
+        Err(ArithmeticError::NegOverflow)
+    );
+    assert_eq!(
+        FixedDecimalI8::try_sum(Vec::<FixedDecimalI8<1>>::new()),
+        Ok(FixedDecimalI8::new(0))
+    );
+}
+
+proptest! {
+    #[test]
+    fn checked_sum_matches_mantissa_sum(v in proptest::collection::vec(any::<i64>(), 0..16)) {
+        let entries = v.iter().map(|n| FixedDecimalI128::<4>::new(*n as i128)).collect::<Vec<_>>();
+        let expected = v.iter().map(|n| *n as i128).sum::<i128>();
+        assert_eq!(FixedDecimalI128::checked_sum(&entries), Some(FixedDecimalI128::new(expected)));
+        assert_eq!(entries.iter().sum::<FixedDecimalI128<4>>(), FixedDecimalI128::<4>::new(expected));
+    }
+}
+
+// Num traits
+
+fn generic_polynomial<N: Num + Copy>(x: N) -> N {
+    x * x + N::one() + N::one()
+}
+
+#[test]
+fn it_works_as_num() {
+    let x = FixedDecimalI64::<2>::from_str("1.50").unwrap();
+    assert_eq!(generic_polynomial(x).to_string(), "4.25");
+    assert_eq!(FixedDecimalI64::<2>::one(), FixedDecimalI64::<2>::ONE);
+    assert_eq!(<FixedDecimalI64<2> as ConstOne>::ONE.to_string(), "1.00");
+    assert!(FixedDecimalI64::<2>::ONE.is_one());
+    assert_eq!(
+        FixedDecimalI64::<2>::from_str_radix("-1.5", 10),
+        FixedDecimalI64::<2>::from_str("-1.5")
+    );
+    assert!(FixedDecimalI64::<2>::from_str_radix("1.5", 16).is_err());
+    assert_eq!(FixedDecimalI64::<2>::max_value(), FixedDecimalI64::<2>::MAX);
+    assert_eq!(
+        FixedDecimalU128::<2>::min_value(),
+        FixedDecimalU128::<2>::MIN
+    );
+}
+
+#[test]
+fn it_works_as_signed() {
+    let a = FixedDecimalI64::<2>::from_str("-1.25").unwrap();
+    let b = FixedDecimalI64::<2>::from_str("0.75").unwrap();
+    assert_eq!(a.abs().to_string(), "1.25");
+    assert_eq!(a.signum().to_string(), "-1.00");
+    assert_eq!(b.signum().to_string(), "1.00");
+    assert_eq!(FixedDecimalI64::<2>::zero().signum().to_string(), "0.00");
+    assert_eq!(b.abs_sub(&a).to_string(), "2.00");
+    assert_eq!(a.abs_sub(&b).to_string(), "0.00");
+    assert!(a.is_negative());
+    assert!(Signed::is_positive(&b));
+    assert!(!FixedDecimalI64::<2>::zero().is_negative());
+    assert!(!Signed::is_positive(&FixedDecimalI64::<2>::zero()));
+
+    fn assert_unsigned<N: Unsigned>(_: N) {}
+    assert_unsigned(FixedDecimalU128::<2>::ONE);
+}
+
+proptest! {
+    #[test]
+    fn signed_works_as_internal(a in any::<i64>()) {
+        let d = FixedDecimalI64::<3>::new(a);
+        assert_eq!(d.is_negative(), a.is_negative());
+        assert_eq!(d.signum().mantissa(), a.signum() * 1_000);
+        if a != i64::MIN {
+            assert_eq!(d.abs().mantissa(), a.abs());
+        }
+    }
+}
+
+#[test]
+fn it_casts_primitives() {
+    let a = FixedDecimalI64::<2>::from_str("-1.99").unwrap();
+    assert_eq!(a.to_i64(), Some(-1));
+    assert_eq!(a.to_u64(), None);
+    assert_eq!((-a).to_u8(), Some(1));
+    assert_eq!(ToPrimitive::to_f64(&a), Some(-1.99));
+    assert_eq!(FixedDecimalI64::<0>::MAX.to_i32(), None);
+    assert_eq!(FixedDecimalU128::<2>::MAX.to_u128(), Some(u128::MAX / 100));
+    // One does not fit at these scales, the integer part is always zero.
+    assert_eq!(FixedDecimalI8::<3>::new(-127).to_i64(), Some(0));
+    assert_eq!(FixedDecimalU8::<3>::MAX.to_u8(), Some(0));
+    assert_eq!(FixedDecimalI64::<19>::MIN.to_i128(), Some(0));
+    assert_eq!(FixedDecimalI128::<39>::MAX.to_u64(), Some(0));
+
+    assert_eq!(
+        FixedDecimalI64::<2>::from_i32(-7).unwrap().to_string(),
+        "-7.00"
+    );
+    assert_eq!(FixedDecimalI8::<1>::from_u64(13), None);
+    assert_eq!(
+        FixedDecimalI8::<1>::from_i128(-12),
+        Some(FixedDecimalI8::new(-120))
+    );
+    assert_eq!(
+        FixedDecimalI64::<2>::from_f64(-1.999).unwrap().to_string(),
+        "-1.99"
+    );
+    assert_eq!(
+        FixedDecimalI64::<2>::from_f32(0.5).unwrap().to_string(),
+        "0.50"
+    );
+    assert_eq!(FixedDecimalI64::<2>::from_f64(f64::NAN), None);
+    assert_eq!(FixedDecimalI64::<2>::from_f64(1e30), None);
+
+    let b: Option<FixedDecimalI128<2>> = cast(i64::MAX);
+    assert_eq!(b.unwrap().to_string(), "9223372036854775807.00");
+    let b: Option<FixedDecimalI128<2>> = cast(2.345f64);
+    assert_eq!(b.unwrap().to_string(), "2.34");
+    let b: Option<FixedDecimalI128<3>> = cast(a);
+    assert_eq!(b.unwrap().to_string(), "-1.990");
+    let b: Option<FixedDecimalU128<0>> = cast(-1i8);
+    assert_eq!(b, None);
+    let b: Option<f64> = cast(a);
+    assert_eq!(b, Some(-1.99));
+    let b: Option<i32> = cast(a);
+    assert_eq!(b, Some(-1));
+}
+
+proptest! {
+    #[test]
+    fn to_primitive_truncates(v in any::<i128>()) {
+        let a = FixedDecimalI128::<4>::new(v);
+        assert_eq!(a.to_i128(), Some(v / 10_000));
+        assert_eq!(a.to_i64(), i64::try_from(v / 10_000).ok());
+        if let Ok(v) = i64::try_from(v / 10_000) {
+            assert_eq!(FixedDecimalI128::<4>::from_i64(v).unwrap().to_i64(), Some(v));
+        }
+    }
+}
+
+// Powers
+
+#[test]
+fn it_raises_to_integer_powers() {
+    let rate = FixedDecimalI128::<18>::from_str("1.0001").unwrap();
+    assert_eq!(rate.powi(365).to_string(), "1.037172411302551930");
+    assert_eq!(rate.powi(0), FixedDecimalI128::<18>::ONE);
+    assert_eq!(
+        FixedDecimalI128::<18>::from_str("1.5")
+            .unwrap()
+            .powi(-20)
+            .to_string(),
+        "0.000300728659821717"
+    );
+    let a = FixedDecimalI64::<1>::from_str("-1.5").unwrap();
+    assert_eq!(a.powi(2).to_string(), "2.2");
+    assert_eq!(a.powi(3).to_string(), "-3.4");
+    assert_eq!(a.powi(-1000).to_string(), "0.0");
+    assert_eq!(
+        FixedDecimalI64::<1>::new(-10).powi(i32::MAX).to_string(),
+        "-1.0"
+    );
+    assert_eq!(FixedDecimalI8::<0>::new(2).checked_powi(7), None);
+    assert_eq!(
+        FixedDecimalI8::<0>::new(-2).checked_powi(7),
+        Some(FixedDecimalI8::MIN)
+    );
+}
+
+#[test]
+#[should_panic(expected = "attempt to multiply with overflow")]
+fn it_panics_on_powi_overflow() {
+    let _ = FixedDecimalI64::<1>::new(15).powi(1000);
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn it_panics_on_powi_of_zero_with_negative_exponent() {
+    let _ = FixedDecimalI64::<1>::new(0).powi(-1);
+}
+
+#[test]
+fn it_raises_to_decimal_powers() {
+    let d = |s| FixedDecimalI128::<18>::from_str(s).unwrap();
+    assert_eq!(d("2").powd(d("0.5")).to_string(), "1.414213562373095049");
+    assert_eq!(d("0.5").powd(d("1.5")).to_string(), "0.353553390593273762");
+    assert_eq!(d("10").powd(d("-0.25")).to_string(), "0.562341325190349080");
+    assert_eq!(
+        d("123.456").powd(d("2.5")).to_string(),
+        "169348.168483259651828132"
+    );
+    assert_eq!(d("4").powd(d("0.5")), d("2"));
+    assert_eq!(d("-2").powd(d("3")), d("-8"));
+    assert_eq!(d("0").powd(d("0.5")), d("0"));
+    assert_eq!(d("0").powd(d("0")), d("1"));
+    assert_eq!(d("-2").checked_powd(d("0.5")), None);
+    assert_eq!(d("0").checked_powd(d("-0.5")), None);
+    assert_eq!(d("1000").checked_powd(d("100.5")), None);
+    assert_eq!(d("1").powd(d("1000000000000000000")), d("1"));
+}
+
+#[test]
+#[should_panic(expected = "attempt to raise a negative number to a fractional power")]
+fn it_panics_on_powd_of_negative_number() {
+    let d = |s| FixedDecimalI64::<2>::from_str(s).unwrap();
+    let _ = d("-2").powd(d("0.5"));
+}
+
+#[test]
+fn it_rounds_powers_of_long_mantissas_once() {
+    // The exact square ends right above a midpoint beyond 60 digits.
+    let a = FixedDecimalI128::<35>::new(125000000000000000000000000000000001);
+    assert_eq!(a.powi(2), a * a);
+    assert_eq!(a.powi(2).mantissa() % 10, 3);
+}
+
+proptest! {
+    #[test]
+    fn powi_rounds_once(a in any::<i32>()) {
+        let a = FixedDecimalI128::<6>::new(a as i128);
+        assert_eq!(a.powi(2), a * a);
+        if a.mantissa() != 0 {
+            assert_eq!(a.powi(-1), FixedDecimalI128::<6>::ONE / a);
+        }
+        assert_eq!(a.powd(FixedDecimalI128::from_int(3)), a.powi(3));
+    }
+
+    #[test]
+    fn powi_rounds_once_at_high_scale(a in any::<i128>(), j in 0..26i128, t in -3..=3i128) {
+        // Squares of `(50 * j + 25) * 10^33 + t` are ties at the scale 35
+        // up to a tail beyond 60 significant digits.
+        for a in [a, (50 * j + 25) * 10i128.pow(33) + t] {
+            let a = FixedDecimalI128::<35>::new(a);
+            assert_eq!(a.checked_powi(2), a.checked_mul(a));
+            assert_eq!((-a).checked_powi(2), a.checked_mul(a));
+            assert_eq!(a.checked_powd(FixedDecimalI128::from_int(2)), a.checked_mul(a));
+        }
+    }
+}
+
+// Roots
+
+#[test]
+fn it_takes_square_roots() {
+    let even = RoundingStrategy::MidpointNearestEven;
+    let d = |s| FixedDecimalI128::<18>::from_str(s).unwrap();
+    assert_eq!(
+        d("2").sqrt(even).unwrap().to_string(),
+        "1.414213562373095049"
+    );
+    assert_eq!(
+        d("2").sqrt(RoundingStrategy::ToZero).unwrap().to_string(),
+        "1.414213562373095048"
+    );
+    assert_eq!(
+        d("12345.678901").sqrt(even).unwrap().to_string(),
+        "111.111110610055554426"
+    );
+    assert_eq!(
+        d("0.0625").sqrt(RoundingStrategy::AwayFromZero),
+        Some(d("0.25"))
+    );
+    assert_eq!(d("0").sqrt(even), Some(d("0")));
+    assert_eq!(d("-0.01").sqrt(even), None);
+    assert_eq!(
+        FixedDecimalI128::<18>::MAX.sqrt(even).unwrap().to_string(),
+        "13043817825.332782212349571806"
+    );
+
+    // sqrt(0.100) = 0.316 does not fit an i8 at scale 3.
+    assert_eq!(FixedDecimalI8::<3>::new(100).sqrt(even), None);
+    assert_eq!(
+        FixedDecimalI8::<1>::new(2).sqrt(even),
+        Some(FixedDecimalI8::new(4))
+    );
+}
+
+#[test]
+fn it_takes_nth_roots() {
+    let d = |s| FixedDecimalI128::<18>::from_str(s).unwrap();
+    assert_eq!(
+        d("10")
+            .nth_root(7, RoundingStrategy::MidpointNearestEven)
+            .unwrap()
+            .to_string(),
+        "1.389495494373137637"
+    );
+    assert_eq!(d("-8").nth_root(3, RoundingStrategy::ToZero), None);
+    assert_eq!(d("8").nth_root(0, RoundingStrategy::ToZero), None);
+    assert_eq!(d("8").nth_root(1, RoundingStrategy::ToZero), Some(d("8")));
+    assert_eq!(
+        d("8").nth_root(3, RoundingStrategy::AwayFromZero),
+        Some(d("2"))
+    );
+    assert_eq!(
+        d("1").nth_root(1000, RoundingStrategy::AwayFromZero),
+        Some(d("1"))
+    );
+}
+
+#[test]
+fn it_takes_roots_of_large_degree() {
+    let even = RoundingStrategy::MidpointNearestEven;
+    let two = FixedDecimalI64::<8>::from_int(2);
+    assert_eq!(two.nth_root(3_000, even).unwrap().to_string(), "1.00023108");
+    assert_eq!(
+        two.nth_root(10_000, even).unwrap().to_string(),
+        "1.00006932"
+    );
+    assert_eq!(
+        FixedDecimalI64::<4>::from_str("12.3456")
+            .unwrap()
+            .nth_root(40, RoundingStrategy::ToZero)
+            .unwrap()
+            .to_string(),
+        "1.0648"
+    );
+    let two = FixedDecimalI64::<0>::new(2);
+    assert_eq!(
+        two.nth_root(10_000_000, even),
+        Some(FixedDecimalI64::new(1))
+    );
+    assert_eq!(
+        two.nth_root(u32::MAX, RoundingStrategy::AwayFromZero),
+        Some(two)
+    );
+    assert_eq!(
+        FixedDecimalI128::<38>::from_str("0.5")
+            .unwrap()
+            .nth_root(u32::MAX, RoundingStrategy::ToZero)
+            .unwrap()
+            .to_string(),
+        "0.99999999983861409577881006491594015975"
+    );
+    assert_eq!(
+        FixedDecimalI128::<38>::from_str("1")
+            .unwrap()
+            .nth_root(u32::MAX, RoundingStrategy::AwayFromZero)
+            .unwrap()
+            .to_string(),
+        "1.00000000000000000000000000000000000000"
+    );
+    // The radicand would have about 215 000 digits, the root can not fit.
+    assert_eq!(
+        FixedDecimalU128::<255>::new(1).nth_root(847, RoundingStrategy::ToZero),
+        None
+    );
+}
+
+proptest! {
+    #[test]
+    fn sqrt_is_correctly_rounded(a in 0..i64::MAX) {
+        let x = FixedDecimalI64::<4>::new(a);
+        let scaled = a as u128 * 10_000;
+        let floor = x.sqrt(RoundingStrategy::ToZero).unwrap().mantissa() as u128;
+        assert!(floor * floor <= scaled && scaled < (floor + 1) * (floor + 1));
+        let nearest = x.sqrt(RoundingStrategy::MidpointNearestEven).unwrap().mantissa() as u128;
+        // `(floor + 0.5)^2 = floor^2 + floor + 0.25`
+        let expected = if scaled > floor * floor + floor { floor + 1 } else { floor };
+        assert_eq!(nearest, expected);
+    }
+}
+
+// Exponential and logarithms
+
+#[test]
+fn it_computes_exponentials() {
+    let d = |s| FixedDecimalI128::<18>::from_str(s).unwrap();
+    assert_eq!(d("1").exp().to_string(), "2.718281828459045235");
+    assert_eq!(d("-3.5").exp().to_string(), "0.030197383422318501");
+    assert_eq!(d("0").exp(), d("1"));
+    assert_eq!(d("-100").exp(), d("0"));
+    assert_eq!(d("80").checked_exp(), None);
+    assert_eq!(
+        FixedDecimalI128::<0>::from_int(88).exp().to_string(),
+        "165163625499400185552832979626485876707"
+    );
+    assert_eq!(FixedDecimalI128::<0>::from_int(89).checked_exp(), None);
+    assert_eq!(
+        FixedDecimalI128::<0>::MIN.checked_exp(),
+        Some(FixedDecimalI128::new(0))
+    );
+}
+
+#[test]
+#[should_panic(expected = "attempt to exponentiate with overflow")]
+fn it_panics_on_exp_overflow() {
+    let _ = FixedDecimalI64::<2>::from_int(100).exp();
+}
+
+#[test]
+fn it_computes_logarithms() {
+    let d = |s| FixedDecimalI128::<18>::from_str(s).unwrap();
+    assert_eq!(d("0.000001").ln().to_string(), "-13.815510557964274104");
+    assert_eq!(d("1").ln(), d("0"));
+    assert_eq!(
+        d("123456789.123").log10().to_string(),
+        "8.091514977601958044"
+    );
+    assert_eq!(d("0.001").log10(), d("-3"));
+    assert_eq!(d("100").log(d("1.5")).to_string(), "11.357747174535145609");
+    assert_eq!(d("0.25").log(d("0.5")), d("2"));
+    assert_eq!(d("0").checked_ln(), None);
+    assert_eq!(d("-1").checked_log10(), None);
+    assert_eq!(d("2").checked_log(d("1")), None);
+    assert_eq!(d("2").checked_log(d("-2")), None);
+    // ln(0.01) = -4.61 does not fit an i8 at scale 2.
+    assert_eq!(FixedDecimalI8::<2>::new(1).checked_ln(), None);
+}
+
+#[test]
+#[should_panic(expected = "attempt to take the logarithm of a non-positive number")]
+fn it_panics_on_ln_of_zero() {
+    let _ = FixedDecimalI64::<2>::new(0).ln();
+}
+
+#[test]
+#[should_panic(expected = "attempt to take the logarithm with an invalid base")]
+fn it_panics_on_log_with_base_one() {
+    let _ = FixedDecimalI64::<2>::new(200).log(FixedDecimalI64::<2>::new(100));
+}
+
+proptest! {
+    #[test]
+    fn ln_inverts_exp(a in 0..20_000_000i64) {
+        // Both results are rounded, the roundtrip is only within one unit.
+        let a = FixedDecimalI128::<6>::new(a as i128);
+        assert!((a.exp().ln() - a).mantissa().abs() <= 1);
+        let b = FixedDecimalI128::<6>::from_int(10).powd(a);
+        assert!((b.log10() - a).mantissa().abs() <= 1);
+    }
+}
+
+// Allocation
+
+#[test]
+fn it_allocates_proportionally() {
+    let d = |s| FixedDecimalI64::<2>::from_str(s).unwrap();
+    let to_strings =
+        |parts: Vec<FixedDecimalI64<2>>| parts.iter().map(ToString::to_string).collect::<Vec<_>>();
+    assert_eq!(to_strings(d("0.05").allocate(&[3u32, 7])), ["0.02", "0.03"]);
+    assert_eq!(
+        to_strings(d("1.00").allocate(&[1u8, 0, 2])),
+        ["0.33", "0.00", "0.67"]
+    );
+    assert_eq!(
+        to_strings(d("-0.02").allocate(&[1u64, 1, 1])),
+        ["-0.01", "-0.01", "0.00"]
+    );
+    assert_eq!(to_strings(d("0.00").allocate(&[1u8, 2])), ["0.00", "0.00"]);
+    assert_eq!(
+        to_strings(d("7.00").allocate(&[u128::MAX, u128::MAX])),
+        ["3.50", "3.50"]
+    );
+    assert_eq!(to_strings(d("0.03").split_evenly(2)), ["0.02", "0.01"]);
+    assert_eq!(
+        to_strings(d("0.01").split_evenly(4)),
+        ["0.01", "0.00", "0.00", "0.00"]
+    );
+
+    let parts = FixedDecimalI8::<0>::MIN.split_evenly(3);
+    let mantissas = parts.iter().map(|part| part.mantissa()).collect::<Vec<_>>();
+    assert_eq!(mantissas, [-43, -43, -42]);
+}
+
+#[test]
+#[should_panic(expected = "attempt to allocate with a zero total ratio")]
+fn it_panics_on_allocation_without_ratios() {
+    let _ = FixedDecimalI64::<2>::new(100).allocate(&[0u8, 0]);
+}
+
+proptest! {
+    #[test]
+    fn allocation_sums_to_amount(amount in any::<i64>(), ratios in proptest::collection::vec(any::<u32>(), 1..10)) {
+        prop_assume!(ratios.iter().any(|ratio| *ratio != 0));
+        let amount = FixedDecimalI64::<2>::new(amount);
+        let parts = amount.allocate(&ratios);
+        assert_eq!(parts.len(), ratios.len());
+        assert_eq!(parts.iter().map(|part| part.mantissa() as i128).sum::<i128>(), amount.mantissa() as i128);
+
+        let total = ratios.iter().map(|ratio| *ratio as i128).sum::<i128>();
+        for (part, ratio) in parts.iter().zip(&ratios) {
+            // Every part is within one unit of its exact share.
+            let exact = amount.mantissa() as i128 * *ratio as i128;
+            assert!((part.mantissa() as i128 * total - exact).abs() < total);
+        }
+    }
+}
+
 // Ord
 proptest! {
     #[test]
@@ -482,6 +2481,68 @@ proptest! {
     }
 }
 
+fn hash_of<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn it_compares_across_scales() {
+    let a = FixedDecimalI64::<1>::from_str("1.5").unwrap();
+    let b = FixedDecimalI64::<3>::from_str("1.500").unwrap();
+    assert_eq!(a, b);
+    assert_eq!(b, a);
+    assert!(a < FixedDecimalI64::<2>::from_str("1.51").unwrap());
+    assert!(-a > FixedDecimalI64::<2>::from_str("-1.51").unwrap());
+    assert!(FixedDecimalI64::<0>::new(-1) < FixedDecimalI64::<18>::new(0));
+    assert!(FixedDecimalI8::<0>::MAX > FixedDecimalI8::<3>::MAX);
+    assert!(FixedDecimalI8::<0>::MIN < FixedDecimalI8::<3>::MIN);
+    assert!(FixedDecimalU128::<0>::MAX > FixedDecimalU128::<38>::MAX);
+    assert_ne!(FixedDecimalU128::<0>::new(1), FixedDecimalU128::<38>::MAX);
+    assert_eq!(
+        FixedDecimalI8::<0>::new(0),
+        FixedDecimalI8::<{ u8::MAX }>::new(0)
+    );
+}
+
+#[test]
+fn it_hashes_consistently_with_eq() {
+    let a = FixedDecimalI64::<1>::from_str("-1.5").unwrap();
+    assert_eq!(
+        hash_of(&a),
+        hash_of(&FixedDecimalI64::<4>::from_str("-1.5").unwrap())
+    );
+    assert_ne!(hash_of(&a), hash_of(&-a));
+    assert_eq!(
+        hash_of(&FixedDecimalI64::<0>::new(100)),
+        hash_of(&FixedDecimalI64::<2>::from_str("100").unwrap())
+    );
+    assert_eq!(
+        hash_of(&FixedDecimalI64::<0>::new(0)),
+        hash_of(&FixedDecimalI64::<7>::new(0))
+    );
+
+    let mut map = HashMap::new();
+    map.insert(FixedDecimalI64::<2>::new(150), "a");
+    assert_eq!(
+        map.get(&FixedDecimalI64::<2>::from_str("1.50").unwrap()),
+        Some(&"a")
+    );
+}
+
+proptest! {
+    #[test]
+    fn cross_scale_cmp_works_as_rescaled(a in any::<i64>(), b in any::<i64>()) {
+        let a = FixedDecimalI128::<2>::new(a as i128);
+        let b = FixedDecimalI128::<6>::new(b as i128);
+        let rescaled = FixedDecimalI128::<6>::new(a.mantissa() * 10_000);
+        assert_eq!(a.partial_cmp(&b), rescaled.partial_cmp(&b));
+        assert_eq!(a == b, rescaled == b);
+        assert_eq!(hash_of(&a), hash_of(&rescaled));
+    }
+}
+
 // Serde
 #[cfg(feature = "serde")]
 mod _serde {